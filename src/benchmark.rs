@@ -1,9 +1,56 @@
-use std::{str::FromStr, time::Instant};
+use std::{
+	fmt::Display,
+	str::FromStr,
+	time::{Duration, Instant},
+};
+
+use crate::{
+	board::Board,
+	error::Error,
+	move_gen::{defs::SliderBackend, MoveGenerator},
+};
+
+pub struct PerftReport {
+	pub depth: usize,
+	// Root moves in UCI notation with the nodes below each of them
+	pub divide: Vec<(String, usize)>,
+	pub nodes: usize,
+	pub elapsed: Duration,
+}
+
+impl PerftReport {
+	pub fn nodes_per_second(&self) -> u64 {
+		let elapsed = self.elapsed.as_secs_f64();
 
-use crate::{board::Board, move_gen::MoveGenerator};
+		if elapsed == 0.0 {
+			return 0;
+		}
+
+		(self.nodes as f64 / elapsed) as u64
+	}
+
+	pub fn to_divide(&self) -> String {
+		let mut divide = String::new();
+
+		for (uci, move_nodes) in &self.divide {
+			divide.push_str(&format!("{uci}: {move_nodes}\n"));
+		}
+
+		divide.push_str(&format!("\nNodes searched: {}\n", self.nodes));
+		divide
+	}
+}
+
+impl Display for PerftReport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "Total time (ms)\t: {}", self.elapsed.as_millis())?;
+		writeln!(f, "Nodes searched\t: {}", self.nodes)?;
+		write!(f, "Nodes/second\t: {}", self.nodes_per_second())
+	}
+}
 
 pub struct Benchmark {
-	pub board: Board,
+	board: Board,
 	move_gen: MoveGenerator,
 }
 
@@ -17,33 +64,60 @@ impl Default for Benchmark {
 }
 
 impl Benchmark {
-	#[allow(dead_code)]
 	pub fn set_fen(&mut self, fen: &str) {
 		self.board = Board::from_str(fen).unwrap();
 	}
 
-	pub fn perft(&mut self, depth: usize) {
+	pub fn board(&self) -> &Board {
+		&self.board
+	}
+
+	pub fn set_backend(&mut self, backend: SliderBackend) -> Result<(), Error> {
+		self.move_gen = MoveGenerator::with_backend(backend)?;
+
+		Ok(())
+	}
+
+	pub fn perft(&mut self, depth: usize) -> PerftReport {
 		let now = Instant::now();
 
-		let mut nodes = 0;
+		let mut divide = Vec::new();
 
-		let move_list = self.move_gen.all_possible(&self.board);
+		if depth > 0 {
+			let move_list = self.move_gen.all_possible(&self.board);
 
-		for _move in move_list {
-			self.board.make_move(_move);
-			let move_nodes = self._perft(depth - 1);
-			self.board.undo_move();
+			for _move in move_list {
+				let uci = self.board.move_to_uci(_move);
 
-			nodes += move_nodes;
-			// println!("{_move}: {move_nodes}");
+				self.board.make_move(_move);
+				let move_nodes = self._perft(depth - 1);
+				self.board.undo_move();
+
+				divide.push((uci, move_nodes));
+			}
+		}
+
+		let nodes = if depth > 0 {
+			divide.iter().map(|(_, move_nodes)| move_nodes).sum()
+		} else {
+			1
+		};
+
+		PerftReport {
+			depth,
+			divide,
+			nodes,
+			elapsed: now.elapsed(),
 		}
+	}
+
+	// Prints the per move node counts the way other engines do, so the output can be diffed
+	pub fn divide(&mut self, depth: usize) -> PerftReport {
+		let report = self.perft(depth);
 
-		let elapsed = now.elapsed().as_millis() as f64;
-		let nodes_per_seconds = ((nodes * 1000) as f64 / elapsed).floor();
+		print!("{}", report.to_divide());
 
-		println!("\nTotal time (ms)\t: {:.0}", elapsed);
-		println!("Nodes searched\t: {nodes}");
-		println!("Nodes/second\t: {}", nodes_per_seconds);
+		report
 	}
 
 	fn _perft(&mut self, depth: usize) -> usize {
@@ -51,6 +125,11 @@ impl Benchmark {
 			return 1;
 		}
 
+		// Leaves are only counted, never made
+		if depth == 1 {
+			return self.move_gen.count_legal(&self.board);
+		}
+
 		let mut nodes = 0;
 
 		let move_list = self.move_gen.all_possible(&self.board);
@@ -0,0 +1,94 @@
+use std::{env, fs, process};
+
+use chess_rust_old::{MagicOptions, MagicTable, Piece};
+
+const USAGE: &str =
+	"usage: magic <rook|bishop> [--seed N] [--bits N] [--overlap] [--candidates N] [--out FILE]";
+
+fn exit_with_usage(message: &str) -> ! {
+	eprintln!("{message}\n{USAGE}");
+	process::exit(1);
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> T {
+	value
+		.and_then(|value| value.parse().ok())
+		.unwrap_or_else(|| exit_with_usage(&format!("Invalid value for {flag}")))
+}
+
+fn main() {
+	let mut args = env::args().skip(1);
+
+	let piece = match args.next().as_deref() {
+		Some("rook") => Piece::Rook,
+		Some("bishop") => Piece::Bishop,
+		_ => exit_with_usage("Expected rook or bishop"),
+	};
+
+	let mut options = MagicOptions {
+		candidates: 16,
+		..Default::default()
+	};
+	let mut out = None;
+
+	while let Some(flag) = args.next() {
+		match flag.as_str() {
+			"--seed" => options.seed = parse_value(&flag, args.next()),
+			"--bits" => options.fixed_bits = Some(parse_value(&flag, args.next())),
+			"--overlap" => options.overlap = true,
+			"--candidates" => options.candidates = parse_value(&flag, args.next()),
+			"--out" => out = Some(parse_value::<String>(&flag, args.next())),
+			_ => exit_with_usage(&format!("Unknown argument: {flag}")),
+		}
+	}
+
+	//* The tables in use, as a baseline */
+	let current = MagicTable::current(piece);
+
+	match current.verify() {
+		Ok(()) => eprintln!(
+			"Current {} table: {} entries",
+			piece.to_full_name(),
+			current.size
+		),
+		Err(error) => eprintln!(
+			"Current {} numbers are broken: {error}",
+			piece.to_full_name()
+		),
+	}
+
+	let table = MagicTable::search(piece, &options).unwrap_or_else(|error| {
+		eprintln!("{error}");
+		process::exit(1);
+	});
+
+	if let Err(error) = table.verify() {
+		eprintln!("Search produced a broken table: {error}");
+		process::exit(1);
+	}
+
+	let shared = (0..64)
+		.filter(|&square_index| table.magics[square_index].nr == current.magics[square_index].nr)
+		.count();
+
+	eprintln!(
+		"Found {} table: {} entries ({:+} against current), {shared}/64 numbers unchanged",
+		piece.to_full_name(),
+		table.size,
+		table.size as i64 - current.size as i64
+	);
+
+	let source = format!(
+		"// Generated by: magic {}\n\n{}",
+		env::args().skip(1).collect::<Vec<_>>().join(" "),
+		table.to_rust_source()
+	);
+
+	match out {
+		Some(path) => fs::write(&path, source).unwrap_or_else(|error| {
+			eprintln!("Could not write {path}: {error}");
+			process::exit(1);
+		}),
+		None => print!("{source}"),
+	}
+}
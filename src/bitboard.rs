@@ -2,7 +2,7 @@ use crate::board::Board;
 
 pub type Bitboard = u64;
 
-pub fn set_bit(bitboard: &mut Bitboard, square_index: u8) {
+pub const fn set_bit(bitboard: &mut Bitboard, square_index: u8) {
 	*bitboard |= 1u64 << square_index
 }
 
@@ -10,7 +10,7 @@ pub fn clear_bit(bitboard: &mut Bitboard, square_index: u8) {
 	*bitboard ^= 1u64 << square_index
 }
 
-pub fn is_occupied(bitboard: Bitboard, square_index: u8) -> bool {
+pub const fn is_occupied(bitboard: Bitboard, square_index: u8) -> bool {
 	bitboard & (1u64 << square_index) != 0
 }
 
@@ -1,10 +1,14 @@
 use crate::{
-	bitboard::{clear_bit, is_occupied, set_bit, Bitboard},
+	bitboard::{clear_bit, get_lsb_index, is_occupied, pop_lsb, set_bit, Bitboard},
 	color::Color,
 	error::Error,
-	move_gen::{defs::Move, PAWN_PUSH_DIRECTION},
-	notation::Notation,
+	move_gen::{
+		defs::{Move, MoveKind},
+		MoveGenerator, CASTLE_ROOK_TARGET, PAWN_PUSH_DIRECTION,
+	},
+	notation::{Notation, Square},
 	piece::{Piece, ALL_PIECES},
+	zobrist::ZOBRIST,
 };
 use std::{
 	fmt::{Display, Formatter, Result},
@@ -12,17 +16,33 @@ use std::{
 };
 
 const CASTLE_ROOK_START: [u8; 4] = [00, 07, 56, 63];
-const CASTLE_ROOK_TARGET: [u8; 4] = [03, 05, 59, 61];
+const CASTLE_KING_START: [u8; 4] = [4, 4, 60, 60];
 
-#[allow(dead_code)]
+const LIGHT_SQUARES: Bitboard = 0x55aa55aa55aa55aa;
+const BACK_RANKS: Bitboard = 0xff000000000000ff;
+const BACK_RANK: [Bitboard; 2] = [0x00000000000000ff, 0xff00000000000000];
+const ENPASSANT_RANK: [Bitboard; 2] = [0x0000ff0000000000, 0x0000000000ff0000];
+
+#[derive(Clone)]
 pub struct Board {
 	pieces: [Bitboard; 6],
 	colors: [Bitboard; 2],
+	mailbox: [Option<(Piece, Color)>; 64],
 
 	color: Color,
 
-	pub castle_rights: usize,
-	pub enpassant: Option<usize>,
+	castle_rights: usize,
+	enpassant: Option<usize>,
+
+	castle_rooks: [u8; 4],
+	chess960: bool,
+	shredder_fen: bool,
+
+	halfmove_clock: usize,
+	fullmove_number: usize,
+
+	hash: u64,
+	hash_history: Vec<u64>,
 
 	move_history: Vec<Move>,
 }
@@ -44,12 +64,9 @@ impl Display for Board {
 				};
 
 				let square_index = Board::to_square_index(rank, file);
-				let piece = self.get_piece_from_square(square_index);
 
-				if let Some(piece) = piece {
-					let is_white = is_occupied(self.colors[0], square_index);
-					let color = if is_white { Color::White } else { Color::Black };
-					let str_piece = Piece::new(piece).to_string(color);
+				if let Some((piece, color)) = self.get_piece_at(square_index) {
+					let str_piece = piece.to_string(color);
 
 					board += &format!("| {} ", str_piece);
 				} else {
@@ -73,13 +90,11 @@ impl FromStr for Board {
 	type Err = Error;
 
 	fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
-		let mut rank = 7u8;
-		let mut file = 0u8;
 		let mut board = Board::new();
 
-		let tokens: Vec<&str> = value.split(' ').collect();
+		let tokens: Vec<&str> = value.split_whitespace().collect();
 
-		if tokens.len() < 4 {
+		if tokens.len() < 4 || tokens.len() > 6 {
 			return Err(Error::InvalidFen {
 				fen: value.to_string(),
 			});
@@ -90,60 +105,110 @@ impl FromStr for Board {
 		let castle_right = tokens[2];
 		let enpassant = tokens[3];
 
-		for char in pieces.chars() {
-			match char {
-				'/' => {
-					rank -= 1;
-					file = 0;
-				}
-				'1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' => {
-					file += char.to_digit(10).unwrap() as u8
-				}
-				'K' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'k' | 'p' | 'n' | 'b' | 'r' | 'q' => {
-					let square_index = Board::to_square_index(rank, file);
-					let color = Color::from(char);
-					let piece = Piece::from(char);
-
-					set_bit(&mut board.pieces[piece.to_index()], square_index);
-					set_bit(&mut board.colors[color.to_index()], square_index);
-					file += 1;
-				}
-				_ => {
-					return Err(Error::InvalidFen {
-						fen: value.to_string(),
-					})
+		let ranks: Vec<&str> = pieces.split('/').collect();
+
+		if ranks.len() != 8 {
+			return Err(Error::InvalidFenRankCount { count: ranks.len() });
+		}
+
+		for (rank, pieces) in (0..8u8).rev().zip(ranks) {
+			let mut file = 0usize;
+
+			for char in pieces.chars() {
+				match char {
+					'1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' => {
+						file += char.to_digit(10).unwrap() as usize
+					}
+					'K' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'k' | 'p' | 'n' | 'b' | 'r' | 'q' => {
+						if file < 8 {
+							let square_index = Board::to_square_index(rank, file as u8);
+							let color = Color::from(char);
+							let piece = Piece::from(char);
+
+							set_bit(&mut board.pieces[piece.to_index()], square_index);
+							set_bit(&mut board.colors[color.to_index()], square_index);
+							board.mailbox[square_index as usize] = Some((piece, color));
+						}
+
+						file += 1;
+					}
+					_ => {
+						return Err(Error::InvalidFenChar {
+							field: "piece placement",
+							char,
+						})
+					}
 				}
 			}
+
+			if file != 8 {
+				return Err(Error::InvalidFenRankLength {
+					rank: rank + 1,
+					length: file,
+				});
+			}
+		}
+
+		for color in [Color::White, Color::Black] {
+			let count = board.get_bitboard(Piece::King, color).count_ones();
+
+			if count != 1 {
+				return Err(Error::InvalidFenKingCount { color, count });
+			}
 		}
 
 		match turn {
 			"w" | "W" => board.color = Color::White,
 			"b" | "B" => board.color = Color::Black,
 			_ => {
-				return Err(Error::InvalidFen {
-					fen: value.to_string(),
+				return Err(Error::InvalidFenField {
+					field: "side to move",
+					value: turn.to_string(),
 				})
 			}
 		}
 
-		for char in castle_right.chars() {
-			match char {
-				'Q' => board.castle_rights |= 0b0001,
-				'K' => board.castle_rights |= 0b0010,
-				'q' => board.castle_rights |= 0b0100,
-				'k' => board.castle_rights |= 0b1000,
-				'-' => continue,
+		if castle_right != "-" {
+			for char in castle_right.chars() {
+				board.parse_castle_right(char)?;
+			}
+		}
+
+		board.chess960 = board.shredder_fen || !board.is_standard_castling();
+
+		if enpassant != "-" {
+			let notation = Notation::from_str(enpassant).map_err(|_| Error::InvalidFenField {
+				field: "en passant square",
+				value: enpassant.to_string(),
+			})?;
+
+			board.enpassant = Some(notation as usize);
+		}
+
+		if let Some(halfmove_clock) = tokens.get(4) {
+			let halfmove_clock: u16 =
+				halfmove_clock.parse().map_err(|_| Error::InvalidFenField {
+					field: "halfmove clock",
+					value: halfmove_clock.to_string(),
+				})?;
+
+			board.halfmove_clock = halfmove_clock as usize;
+		}
+
+		if let Some(fullmove_number) = tokens.get(5) {
+			board.fullmove_number = match fullmove_number.parse() {
+				Ok(number) if number > 0 => number,
 				_ => {
-					return Err(Error::InvalidFen {
-						fen: value.to_string(),
+					return Err(Error::InvalidFenField {
+						field: "fullmove number",
+						value: fullmove_number.to_string(),
 					})
 				}
-			}
+			};
 		}
 
-		if let Ok(notation) = Notation::from_str(&enpassant) {
-			board.enpassant = Some(notation as usize)
-		}
+		board.validate()?;
+		board.hash = board.compute_hash();
 
 		Ok(board)
 	}
@@ -154,24 +219,390 @@ impl Board {
 		Self {
 			pieces: [0; 6],
 			colors: [0; 2],
+			mailbox: [None; 64],
 			color: Color::White,
 			castle_rights: 0,
 			enpassant: None,
+			castle_rooks: CASTLE_ROOK_START,
+			chess960: false,
+			shredder_fen: false,
+			halfmove_clock: 0,
+			fullmove_number: 1,
+			hash: 0,
+			hash_history: Vec::new(),
 			move_history: Vec::new(),
 		}
 	}
 
-	pub fn to_square_index(rank: u8, file: u8) -> u8 {
+	pub const fn to_square_index(rank: u8, file: u8) -> u8 {
 		rank * 8 + file
 	}
 
-	pub fn square_to_rank_file(square_index: u8) -> (u8, u8) {
+	pub const fn square_to_rank_file(square_index: u8) -> (u8, u8) {
 		let rank = square_index / 8;
 		let file = square_index - rank * 8;
 
 		(rank, file)
 	}
 
+	// Accepts KQkq, X-FEN and Shredder-FEN castling rights
+	fn parse_castle_right(&mut self, char: char) -> std::result::Result<(), Error> {
+		let color = Color::from(char);
+		let back_rank = if color == Color::White { 0 } else { 7 };
+
+		let king_square = get_lsb_index(self.get_bitboard(Piece::King, color));
+		let bb_rooks = self.get_bitboard(Piece::Rook, color) & BACK_RANK[color.to_index()];
+
+		let bb_kingside_rooks = bb_rooks & !(u64::MAX >> (63 - king_square));
+		let bb_queenside_rooks = bb_rooks & ((1 << king_square) - 1);
+
+		let outer_kingside_rook =
+			(bb_kingside_rooks != 0).then(|| 63 - bb_kingside_rooks.leading_zeros() as u8);
+		let outer_queenside_rook =
+			(bb_queenside_rooks != 0).then(|| get_lsb_index(bb_queenside_rooks));
+
+		let rook_square = match char.to_ascii_lowercase() {
+			'k' => outer_kingside_rook,
+			'q' => outer_queenside_rook,
+			'a'..='h' => {
+				let file = char.to_ascii_lowercase() as u8 - b'a';
+				let rook_square = Board::to_square_index(back_rank, file);
+
+				// File letters are only needed in X-FEN when the rook is not the outermost one
+				let is_outermost = Some(rook_square) == outer_kingside_rook
+					|| Some(rook_square) == outer_queenside_rook;
+
+				self.shredder_fen |= is_outermost;
+
+				Some(rook_square).filter(|&square_index| is_occupied(bb_rooks, square_index))
+			}
+			_ => {
+				return Err(Error::InvalidFenChar {
+					field: "castling rights",
+					char,
+				})
+			}
+		}
+		.ok_or(Error::InvalidFenCastleRight { right: char })?;
+
+		let is_kingside = rook_square > king_square;
+		let castle_index = color.to_index() * 2 + is_kingside as usize;
+
+		if self.castle_rights & (1 << castle_index) != 0 {
+			return Err(Error::InvalidFenChar {
+				field: "castling rights",
+				char,
+			});
+		}
+
+		self.castle_rights |= 1 << castle_index;
+		self.castle_rooks[castle_index] = rook_square;
+
+		Ok(())
+	}
+
+	fn is_standard_castling(&self) -> bool {
+		(0..4).all(|castle_index| {
+			if self.castle_rights & (1 << castle_index) == 0 {
+				return true;
+			}
+
+			let color = if castle_index < 2 {
+				Color::White
+			} else {
+				Color::Black
+			};
+
+			let bb_king = self.get_bitboard(Piece::King, color);
+
+			is_occupied(bb_king, CASTLE_KING_START[castle_index])
+				&& self.castle_rooks[castle_index] == CASTLE_ROOK_START[castle_index]
+		})
+	}
+
+	fn validate(&self) -> std::result::Result<(), Error> {
+		let bb_back_rank_pawns = self.pieces[Piece::Pawn.to_index()] & BACK_RANKS;
+
+		if bb_back_rank_pawns != 0 {
+			let square_index = get_lsb_index(bb_back_rank_pawns) as usize;
+
+			return Err(Error::InvalidFenPawnRank {
+				square: Notation::from(square_index).to_string(),
+			});
+		}
+
+		for castle_index in 0..4 {
+			if self.castle_rights & (1 << castle_index) == 0 {
+				continue;
+			}
+
+			let color = if castle_index < 2 {
+				Color::White
+			} else {
+				Color::Black
+			};
+
+			let king_square = get_lsb_index(self.get_bitboard(Piece::King, color));
+			let rook_square = self.castle_rooks[castle_index];
+
+			// The king has to share the back rank with its castling rook
+			if king_square / 8 != rook_square / 8 {
+				return Err(Error::InvalidFenCastleRight {
+					right: self.castle_right_char(castle_index),
+				});
+			}
+		}
+
+		if let Some(square_index) = self.enpassant {
+			let color_index = self.color.to_index();
+			let bb_occupancy = self.get_occupancy();
+			let bb_opp_pawns = self.get_bitboard(Piece::Pawn, !self.color);
+
+			// The pawn that double pushed stands in front of the square and came from behind it
+			let is_valid = is_occupied(ENPASSANT_RANK[color_index], square_index as u8) && {
+				let pawn_square = square_index as i8 - PAWN_PUSH_DIRECTION[color_index];
+				let start_square = square_index as i8 + PAWN_PUSH_DIRECTION[color_index];
+
+				is_occupied(bb_opp_pawns, pawn_square as u8)
+					&& !is_occupied(bb_occupancy, square_index as u8)
+					&& !is_occupied(bb_occupancy, start_square as u8)
+			};
+
+			if !is_valid {
+				return Err(Error::InvalidFenEnpassant {
+					square: Notation::from(square_index).to_string(),
+				});
+			}
+		}
+
+		let inactive = !self.color;
+		let opp_king_square = get_lsb_index(self.get_bitboard(Piece::King, inactive));
+		let opp_king_square = Notation::from(opp_king_square as usize);
+
+		if MoveGenerator::default().is_attacked_by(self, opp_king_square, self.color) {
+			return Err(Error::InvalidFenOpponentInCheck { color: inactive });
+		}
+
+		Ok(())
+	}
+
+	pub fn to_fen(&self) -> String {
+		let mut fen = String::new();
+
+		for rank in (0..8).rev() {
+			let mut empty = 0;
+
+			for file in 0..8 {
+				let square_index = Board::to_square_index(rank, file);
+
+				if let Some((piece, color)) = self.get_piece_at(square_index) {
+					if empty > 0 {
+						fen += &empty.to_string();
+						empty = 0;
+					}
+
+					fen += &piece.to_string(color);
+				} else {
+					empty += 1;
+				}
+			}
+
+			if empty > 0 {
+				fen += &empty.to_string();
+			}
+
+			if rank > 0 {
+				fen += "/";
+			}
+		}
+
+		fen += match self.color {
+			Color::White => " w ",
+			Color::Black => " b ",
+		};
+
+		if self.castle_rights == 0 {
+			fen += "-";
+		} else {
+			for castle_index in [1, 0, 3, 2] {
+				if self.castle_rights & (1 << castle_index) != 0 {
+					fen.push(self.castle_right_char(castle_index));
+				}
+			}
+		}
+
+		match self.enpassant {
+			Some(square_index) => fen += &format!(" {}", Notation::from(square_index)),
+			None => fen += " -",
+		}
+
+		fen += &format!(" {} {}", self.halfmove_clock, self.fullmove_number);
+
+		fen
+	}
+
+	fn castle_right_char(&self, castle_index: usize) -> char {
+		let color = if castle_index < 2 {
+			Color::White
+		} else {
+			Color::Black
+		};
+
+		let is_kingside = castle_index % 2 == 1;
+		let rook_square = self.castle_rooks[castle_index];
+		let bb_rooks = self.get_bitboard(Piece::Rook, color) & BACK_RANK[color.to_index()];
+
+		// X-FEN only names the rook by its file if another rook stands further out
+		let bb_outer_rooks = if is_kingside {
+			bb_rooks & !(u64::MAX >> (63 - rook_square))
+		} else {
+			bb_rooks & ((1 << rook_square) - 1)
+		};
+
+		let char = if self.shredder_fen || bb_outer_rooks != 0 {
+			(b'a' + rook_square % 8) as char
+		} else if is_kingside {
+			'k'
+		} else {
+			'q'
+		};
+
+		if color == Color::White {
+			char.to_ascii_uppercase()
+		} else {
+			char
+		}
+	}
+
+	pub fn is_chess960(&self) -> bool {
+		self.chess960
+	}
+
+	pub fn set_chess960(&mut self, chess960: bool) {
+		self.chess960 = chess960;
+	}
+
+	pub(crate) fn get_castle_rook(&self, castle_index: usize) -> u8 {
+		self.castle_rooks[castle_index]
+	}
+
+	pub fn move_to_uci(&self, to_move: Move) -> String {
+		// UCI_Chess960 encodes castling as the king capturing its own rook
+		if self.chess960 && to_move.kind() == MoveKind::Castle {
+			let castle_index = to_move.get_castle_index();
+			let king_square = to_move.get_start_square() as usize;
+			let rook_square = self.castle_rooks[castle_index] as usize;
+
+			return format!(
+				"{}{}",
+				Notation::from(king_square),
+				Notation::from(rook_square)
+			);
+		}
+
+		to_move.to_string()
+	}
+
+	pub fn hash(&self) -> u64 {
+		self.hash
+	}
+
+	pub fn repetition_count(&self) -> usize {
+		let mut count = 1;
+
+		// Positions before the last irreversible move can never repeat
+		let plies = usize::min(self.halfmove_clock, self.hash_history.len());
+		let history = self.hash_history.iter().rev().take(plies);
+
+		// Only every second position has the same side to move
+		for hash in history.skip(1).step_by(2) {
+			if *hash == self.hash {
+				count += 1;
+			}
+		}
+
+		count
+	}
+
+	pub fn is_threefold(&self) -> bool {
+		self.repetition_count() >= 3
+	}
+
+	pub fn is_fivefold(&self) -> bool {
+		self.repetition_count() >= 5
+	}
+
+	pub fn is_insufficient_material(&self) -> bool {
+		let bb_mating_pieces = self.pieces[Piece::Pawn.to_index()]
+			| self.pieces[Piece::Rook.to_index()]
+			| self.pieces[Piece::Queen.to_index()];
+
+		if bb_mating_pieces != 0 {
+			return false;
+		}
+
+		let bb_knights = self.pieces[Piece::Knight.to_index()];
+		let bb_bishops = self.pieces[Piece::Bishop.to_index()];
+
+		// King against king, or against king and a single minor piece
+		if (bb_knights | bb_bishops).count_ones() <= 1 {
+			return true;
+		}
+
+		// Only bishops left and all of them on the same square color
+		bb_knights == 0 && (bb_bishops & LIGHT_SQUARES == 0 || bb_bishops & !LIGHT_SQUARES == 0)
+	}
+
+	fn compute_hash(&self) -> u64 {
+		let mut hash = 0;
+
+		for piece in ALL_PIECES {
+			for color in [Color::White, Color::Black] {
+				let mut bitboard = self.get_bitboard(piece, color);
+
+				while bitboard > 0 {
+					let square_index = pop_lsb(&mut bitboard);
+					hash ^= ZOBRIST.piece(piece.to_index(), color.to_index(), square_index);
+				}
+			}
+		}
+
+		if self.color == Color::Black {
+			hash ^= ZOBRIST.color();
+		}
+
+		hash ^= ZOBRIST.castle_rights(self.castle_rights);
+		hash ^= self.enpassant_hash();
+
+		hash
+	}
+
+	// Polyglot only hashes the en passant file when a pawn of the side to move can take on it
+	fn enpassant_hash(&self) -> u64 {
+		let Some(square_index) = self.enpassant else {
+			return 0;
+		};
+
+		let file = square_index % 8;
+		let pawn_square = (square_index as i8 - PAWN_PUSH_DIRECTION[self.color.to_index()]) as u8;
+
+		let mut bb_capturers = 0;
+
+		if file > 0 {
+			set_bit(&mut bb_capturers, pawn_square - 1);
+		}
+
+		if file < 7 {
+			set_bit(&mut bb_capturers, pawn_square + 1);
+		}
+
+		if self.get_bitboard(Piece::Pawn, self.color) & bb_capturers == 0 {
+			return 0;
+		}
+
+		ZOBRIST.enpassant(self.enpassant)
+	}
+
 	pub fn get_color(&self) -> Color {
 		self.color
 	}
@@ -188,19 +619,45 @@ impl Board {
 		self.colors[color.to_index()]
 	}
 
-	fn get_piece_from_square(&self, square_index: u8) -> Option<usize> {
-		let mut piece_on_square = None;
+	pub fn has_kingside_castle_right(&self, color: Color) -> bool {
+		self.castle_rights & (1 << (color.to_index() * 2 + 1)) != 0
+	}
 
-		for piece in ALL_PIECES {
-			let piece_index = piece.to_index();
+	pub fn has_queenside_castle_right(&self, color: Color) -> bool {
+		self.castle_rights & (1 << (color.to_index() * 2)) != 0
+	}
 
-			if is_occupied(self.pieces[piece_index], square_index) {
-				piece_on_square = Some(piece_index);
-				break;
-			}
-		}
+	pub(crate) fn get_castle_rights(&self) -> usize {
+		self.castle_rights
+	}
+
+	// The square behind a pawn that just moved two squares, whether or not it can be taken
+	pub fn enpassant(&self) -> Option<Square> {
+		self.enpassant.map(Square::from)
+	}
+
+	pub(crate) fn get_enpassant(&self) -> Option<usize> {
+		self.enpassant
+	}
+
+	pub fn halfmove_clock(&self) -> usize {
+		self.halfmove_clock
+	}
+
+	pub fn fullmove_number(&self) -> usize {
+		self.fullmove_number
+	}
+
+	pub fn piece_at(&self, square: Square) -> Option<(Piece, Color)> {
+		self.get_piece_at(square.to_index() as u8)
+	}
 
-		piece_on_square
+	pub(crate) fn get_piece_at(&self, square_index: u8) -> Option<(Piece, Color)> {
+		self.mailbox[square_index as usize]
+	}
+
+	fn get_piece_from_square(&self, square_index: u8) -> Option<usize> {
+		self.mailbox[square_index as usize].map(|(piece, _)| piece.to_index())
 	}
 
 	fn _move_bit(&mut self, piece: usize, color: usize, start_square: u8, target_square: u8) {
@@ -212,11 +669,21 @@ impl Board {
 
 		clear_bit(bb_ally, start_square);
 		set_bit(bb_ally, target_square);
+
+		self.mailbox[start_square as usize] = None;
+		self.mailbox[target_square as usize] = Some((Piece::new(piece), Color::new(color)));
+
+		self.hash ^= ZOBRIST.piece(piece, color, start_square);
+		self.hash ^= ZOBRIST.piece(piece, color, target_square);
 	}
 
 	fn _capture_bit(&mut self, captured: usize, color: usize, target_square: u8) {
 		clear_bit(&mut self.pieces[captured], target_square);
 		clear_bit(&mut self.colors[color], target_square);
+
+		self.mailbox[target_square as usize] = None;
+
+		self.hash ^= ZOBRIST.piece(captured, color, target_square);
 	}
 
 	fn _undo_move_bit(&mut self, piece: usize, color: usize, start_square: u8, target_square: u8) {
@@ -226,37 +693,81 @@ impl Board {
 	fn _undo_capture_bit(&mut self, captured: usize, color: usize, target_square: u8) {
 		set_bit(&mut self.pieces[captured], target_square);
 		set_bit(&mut self.colors[color], target_square);
+
+		self.mailbox[target_square as usize] = Some((Piece::new(captured), Color::new(color)));
+
+		self.hash ^= ZOBRIST.piece(captured, color, target_square);
 	}
 
-	fn _promotion(&mut self, piece: usize, target_square: u8) {
-		clear_bit(&mut self.pieces[Piece::Pawn.to_index()], target_square);
+	fn _promotion(&mut self, piece: usize, color: usize, target_square: u8) {
+		let pawn = Piece::Pawn.to_index();
+
+		clear_bit(&mut self.pieces[pawn], target_square);
 		set_bit(&mut self.pieces[piece], target_square);
+
+		self.mailbox[target_square as usize] = Some((Piece::new(piece), Color::new(color)));
+
+		self.hash ^= ZOBRIST.piece(pawn, color, target_square);
+		self.hash ^= ZOBRIST.piece(piece, color, target_square);
 	}
 
-	fn _undo_promotion(&mut self, piece: usize, target_square: u8) {
+	fn _undo_promotion(&mut self, piece: usize, color: usize, target_square: u8) {
+		let pawn = Piece::Pawn.to_index();
+
 		clear_bit(&mut self.pieces[piece], target_square);
+		set_bit(&mut self.pieces[pawn], target_square);
+
+		self.mailbox[target_square as usize] = Some((Piece::Pawn, Color::new(color)));
+
+		self.hash ^= ZOBRIST.piece(piece, color, target_square);
+		self.hash ^= ZOBRIST.piece(pawn, color, target_square);
 	}
 
 	pub fn make_move(&mut self, mut to_move: Move) {
+		debug_assert!(
+			MoveGenerator::default().is_pseudo_legal(self, to_move),
+			"Move {to_move} does not fit the position"
+		);
+
 		let color = self.color.to_index();
 		let inactive = (!self.color).to_index();
 
-		let piece_moved = to_move.get_moved_piece();
+		let piece_moved = to_move.piece();
 		let start_square = to_move.get_start_square();
 		let target_square = to_move.get_target_square();
-		let piece_captured = self.get_piece_from_square(target_square);
+		let move_kind = to_move.kind();
+
+		// A castling king may land on its own rook
+		let is_castle = move_kind == MoveKind::Castle;
+		let piece_captured = if is_castle {
+			None
+		} else {
+			self.get_piece_from_square(target_square)
+		};
 
 		to_move.set_prev_enpassant(self.enpassant);
 		to_move.set_prev_castle_rights(self.castle_rights);
+		to_move.set_prev_halfmove_clock(self.halfmove_clock);
+		self.hash_history.push(self.hash);
+
+		self.hash ^= ZOBRIST.castle_rights(self.castle_rights);
+		self.hash ^= self.enpassant_hash();
 		self.enpassant = None;
 
+		// Pawn moves and captures are irreversible, so they reset the halfmove clock
+		if piece_moved == Piece::Pawn || piece_captured.is_some() {
+			self.halfmove_clock = 0;
+		} else {
+			self.halfmove_clock += 1;
+		}
+
 		if let Some(piece) = piece_captured {
 			self._capture_bit(piece, inactive, target_square);
 			to_move.set_piece_captured(piece);
 
 			// Is rook captured
-			if piece == 4 {
-				let castle_index = CASTLE_ROOK_START.iter().position(|&i| i == target_square);
+			if piece == Piece::Rook.to_index() {
+				let castle_index = self.castle_rooks.iter().position(|&i| i == target_square);
 
 				if let Some(castle_index) = castle_index {
 					self.castle_rights &= !(1 << castle_index)
@@ -264,33 +775,28 @@ impl Board {
 			}
 		}
 
-		self._move_bit(piece_moved, color, start_square, target_square);
+		// The rook is lifted first, since the king may land on its start square
+		if is_castle {
+			let castle_index = to_move.get_castle_index();
+			let rook_start = self.castle_rooks[castle_index];
 
-		let i8_start_square = start_square as i8;
-		let i8_target_square = target_square as i8;
+			self._capture_bit(Piece::Rook.to_index(), color, rook_start);
+		}
+
+		self._move_bit(piece_moved.to_index(), color, start_square, target_square);
 
-		let move_type = to_move.get_move_type();
+		let i8_target_square = target_square as i8;
 
 		match piece_moved {
-			//King
-			0 => {
+			Piece::King => {
 				// If a king moved then clear color castling rights
 				match self.color {
 					Color::White => self.castle_rights &= 0b1100,
 					Color::Black => self.castle_rights &= 0b0011,
 				}
 			}
-			// Pawn
-			1 => {
-				// Set enpassant if move is a double push
-				if i8::abs(i8_start_square - i8_target_square) == 16 {
-					self.enpassant = Some((i8_target_square - PAWN_PUSH_DIRECTION[color]) as usize);
-				}
-			}
-
-			// Rook
-			4 => {
-				let castle_index = CASTLE_ROOK_START.iter().position(|&i| i == start_square);
+			Piece::Rook => {
+				let castle_index = self.castle_rooks.iter().position(|&i| i == start_square);
 
 				if let Some(castle_index) = castle_index {
 					self.castle_rights &= !(1 << castle_index)
@@ -299,35 +805,41 @@ impl Board {
 			_ => {}
 		}
 
-		match move_type {
-			// Enpassant
-			0b0001 => {
+		match move_kind {
+			MoveKind::DoublePush => {
+				self.enpassant = Some((i8_target_square - PAWN_PUSH_DIRECTION[color]) as usize);
+			}
+			MoveKind::EnPassant => {
 				let pawn_square = i8_target_square - PAWN_PUSH_DIRECTION[color];
-				let piece_captured = piece_moved;
 
-				self._capture_bit(piece_captured, inactive, pawn_square as u8);
+				self._capture_bit(Piece::Pawn.to_index(), inactive, pawn_square as u8);
 			}
-			// Promotion
-			0b0010 => {
-				let piece = to_move.get_promotion();
-
-				self._promotion(piece, target_square);
+			MoveKind::Promotion => {
+				if let Some(piece) = to_move.promotion() {
+					self._promotion(piece.to_index(), color, target_square);
+				}
 			}
-			// Castle
-			0b0100 => {
-				let castle_right = to_move.get_castle_right();
-				let castle_index = castle_right.trailing_zeros() as usize;
-
-				let rook_start = CASTLE_ROOK_START[castle_index];
+			MoveKind::Castle => {
+				let castle_index = to_move.get_castle_index();
 				let rook_target = CASTLE_ROOK_TARGET[castle_index];
 
-				self._move_bit(Piece::Rook.to_index(), color, rook_start, rook_target);
+				self._undo_capture_bit(Piece::Rook.to_index(), color, rook_target);
 			}
 			_ => {}
 		}
 
+		if self.color == Color::Black {
+			self.fullmove_number += 1;
+		}
+
+		self.hash ^= ZOBRIST.castle_rights(self.castle_rights);
+		self.hash ^= ZOBRIST.color();
+
 		self.color = !self.color;
+		self.hash ^= self.enpassant_hash();
 		self.move_history.push(to_move);
+
+		debug_assert_eq!(self.hash, self.compute_hash(), "Incremental hash mismatch");
 	}
 
 	pub fn undo_move(&mut self) -> bool {
@@ -335,50 +847,66 @@ impl Board {
 			let color = (!self.color).to_index();
 			let inactive = self.color.to_index();
 
-			let piece_moved = prev_move.get_moved_piece();
+			let piece_moved = prev_move.piece().to_index();
 			let start_square = prev_move.get_start_square();
 			let target_square = prev_move.get_target_square();
 			let piece_captured = prev_move.get_piece_captured();
 
-			self._undo_move_bit(piece_moved, color, start_square, target_square);
-
 			let i8_target_square = target_square as i8;
-			let move_type = prev_move.get_move_type();
+			let move_kind = prev_move.kind();
 
-			match move_type {
-				// Enpassant
-				0b0001 => {
+			match move_kind {
+				MoveKind::EnPassant => {
 					let pawn_square = i8_target_square - PAWN_PUSH_DIRECTION[color];
-					let piece_captured = piece_moved;
 
-					self._undo_capture_bit(piece_captured, inactive, pawn_square as u8);
+					self._undo_capture_bit(Piece::Pawn.to_index(), inactive, pawn_square as u8);
 				}
-				// Promotion
-				0b0010 => {
-					let piece = prev_move.get_promotion();
-
-					self._undo_promotion(piece, target_square);
+				MoveKind::Promotion => {
+					if let Some(piece) = prev_move.promotion() {
+						self._undo_promotion(piece.to_index(), color, target_square);
+					}
 				}
-				// Castle
-				0b0100 => {
-					let castle_right = prev_move.get_castle_right();
-					let castle_index = castle_right.trailing_zeros() as usize;
-
-					let rook_start = CASTLE_ROOK_START[castle_index];
+				MoveKind::Castle => {
+					let castle_index = prev_move.get_castle_index();
 					let rook_target = CASTLE_ROOK_TARGET[castle_index];
 
-					self._undo_move_bit(Piece::Rook.to_index(), color, rook_start, rook_target);
+					self._capture_bit(Piece::Rook.to_index(), color, rook_target);
 				}
 				_ => {}
 			}
 
+			// Promoted pieces are turned back into a pawn before moving back
+			self._undo_move_bit(piece_moved, color, start_square, target_square);
+
+			if move_kind == MoveKind::Castle {
+				let castle_index = prev_move.get_castle_index();
+				let rook_start = self.castle_rooks[castle_index];
+
+				self._undo_capture_bit(Piece::Rook.to_index(), color, rook_start);
+			}
+
 			if let Some(piece) = piece_captured {
 				self._undo_capture_bit(piece, inactive, target_square);
 			}
 
+			self.hash ^= ZOBRIST.castle_rights(self.castle_rights);
+			self.hash ^= self.enpassant_hash();
+			self.hash ^= ZOBRIST.color();
+
 			self.color = !self.color;
 			self.enpassant = prev_move.get_prev_enpassant();
 			self.castle_rights = prev_move.get_prev_castle_rights();
+			self.halfmove_clock = prev_move.get_prev_halfmove_clock();
+
+			self.hash ^= ZOBRIST.castle_rights(self.castle_rights);
+			self.hash ^= self.enpassant_hash();
+			self.hash_history.pop();
+
+			if self.color == Color::Black {
+				self.fullmove_number -= 1;
+			}
+
+			debug_assert_eq!(self.hash, self.compute_hash(), "Incremental hash mismatch");
 
 			return true;
 		}
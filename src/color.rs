@@ -3,7 +3,7 @@ use std::{
 	ops::Not,
 };
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Color {
 	White,
 	Black,
@@ -46,6 +46,14 @@ impl Display for Color {
 }
 
 impl Color {
+	pub fn new(color_index: usize) -> Self {
+		match color_index {
+			0 => Color::White,
+			1 => Color::Black,
+			_ => panic!(),
+		}
+	}
+
 	pub fn to_index(&self) -> usize {
 		*self as usize
 	}
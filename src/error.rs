@@ -1,10 +1,60 @@
 use failure::Fail;
 
+use crate::color::Color;
+
 #[derive(Clone, Debug, Fail)]
 pub enum Error {
 	#[fail(display = "Invalid FEN string: {}", fen)]
 	InvalidFen { fen: String },
 
+	#[fail(display = "Invalid character '{}' in FEN {}", char, field)]
+	InvalidFenChar { field: &'static str, char: char },
+
+	#[fail(display = "Invalid FEN {}: {}", field, value)]
+	InvalidFenField { field: &'static str, value: String },
+
+	#[fail(display = "Invalid FEN rank count: expected 8, found {}", count)]
+	InvalidFenRankCount { count: usize },
+
+	#[fail(display = "Invalid FEN rank {}: {} squares", rank, length)]
+	InvalidFenRankLength { rank: u8, length: usize },
+
+	#[fail(display = "Invalid FEN: expected one {} king, found {}", color, count)]
+	InvalidFenKingCount { color: Color, count: u32 },
+
+	#[fail(display = "Invalid FEN: pawn on back rank square {}", square)]
+	InvalidFenPawnRank { square: String },
+
+	#[fail(display = "Invalid FEN castling right: {}", right)]
+	InvalidFenCastleRight { right: char },
+
+	#[fail(display = "Impossible FEN en passant square: {}", square)]
+	InvalidFenEnpassant { square: String },
+
+	#[fail(display = "Invalid FEN: {} is in check out of turn", color)]
+	InvalidFenOpponentInCheck { color: Color },
+
+	#[fail(display = "Invalid UCI move: {}", uci)]
+	InvalidUciMove { uci: String },
+
+	#[fail(display = "Invalid SAN move: {}", san)]
+	InvalidSanMove { san: String },
+
+	#[fail(display = "Ambiguous SAN move: {}", san)]
+	AmbiguousMove { san: String },
+
+	#[fail(display = "Illegal move: {}", notation)]
+	IllegalMove { notation: String },
+
+	#[fail(display = "Magic number for {} maps two attacks to one slot", square)]
+	InvalidMagic { square: String },
+
+	#[fail(display = "Cannot index the blockers of {} with {} bits", square, bits)]
+	InvalidMagicBits { square: String, bits: u32 },
+
+	#[fail(display = "Slider backend not supported by this CPU: {}", backend)]
+	UnsupportedBackend { backend: String },
+
 	#[fail(display = "Invalid str: {}", str)]
 	InvalidStr { str: String },
 }
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameStatus {
+	Ongoing,
+	Checkmate,
+	Stalemate,
+	FiftyMoveRule,
+	SeventyFiveMoveRule,
+	InsufficientMaterial,
+	ThreefoldRepetition,
+	FivefoldRepetition,
+}
+
+impl Display for GameStatus {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(
+			f,
+			"{}",
+			match self {
+				GameStatus::Ongoing => "Ongoing",
+				GameStatus::Checkmate => "Checkmate",
+				GameStatus::Stalemate => "Stalemate",
+				GameStatus::FiftyMoveRule => "Fifty-move rule",
+				GameStatus::SeventyFiveMoveRule => "Seventy-five-move rule",
+				GameStatus::InsufficientMaterial => "Insufficient material",
+				GameStatus::ThreefoldRepetition => "Threefold repetition",
+				GameStatus::FivefoldRepetition => "Fivefold repetition",
+			}
+		)
+	}
+}
+
+impl GameStatus {
+	pub fn is_over(&self) -> bool {
+		!matches!(
+			self,
+			GameStatus::Ongoing | GameStatus::FiftyMoveRule | GameStatus::ThreefoldRepetition
+		)
+	}
+
+	pub fn is_claimable(&self) -> bool {
+		matches!(
+			self,
+			GameStatus::FiftyMoveRule | GameStatus::ThreefoldRepetition
+		)
+	}
+}
@@ -0,0 +1,29 @@
+mod benchmark;
+mod bitboard;
+mod board;
+mod color;
+mod error;
+mod game_status;
+#[cfg(any(not(feature = "kogge-stone-only"), feature = "magic-search"))]
+mod magic;
+mod move_gen;
+mod notation;
+mod piece;
+mod zobrist;
+
+pub use benchmark::{Benchmark, PerftReport};
+pub use bitboard::Bitboard;
+pub use board::Board;
+pub use color::Color;
+pub use error::Error;
+pub use game_status::GameStatus;
+#[cfg(feature = "magic-search")]
+pub use magic::{Magic, MagicOptions, MagicTable};
+pub use move_gen::{
+	context::AttackContext,
+	defs::{CheckKind, Move, MoveKind, SliderBackend},
+	move_list::MoveList,
+	MoveGenerator,
+};
+pub use notation::{Notation, Square};
+pub use piece::Piece;
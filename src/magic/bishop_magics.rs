@@ -0,0 +1,73 @@
+// Generated by: magic bishop --overlap --candidates 256 --out src/magic/bishop_magics.rs
+
+use crate::magic::Magic;
+
+pub const BISHOP_TABLE_SIZE: usize = 4862;
+
+#[rustfmt::skip]
+pub const BISHOP_MAGICS: [Magic; 64] = [
+	Magic { mask: 0x0040201008040200, shift: 58, offset: 0x0000000000000dcf, nr: 0x002008018080840d },
+	Magic { mask: 0x0000402010080400, shift: 59, offset: 0x0000000000000e7f, nr: 0x0643881208d00200 },
+	Magic { mask: 0x0000004020100a00, shift: 59, offset: 0x0000000000000e9a, nr: 0x42b0340290085004 },
+	Magic { mask: 0x0000000040221400, shift: 59, offset: 0x0000000000000eb8, nr: 0x2829020200428881 },
+	Magic { mask: 0x0000000002442800, shift: 59, offset: 0x0000000000000ed6, nr: 0x0004030204040989 },
+	Magic { mask: 0x0000000204085000, shift: 59, offset: 0x0000000000000ef5, nr: 0x2200a09040004100 },
+	Magic { mask: 0x0000020408102000, shift: 59, offset: 0x0000000000000f09, nr: 0x0000420611018001 },
+	Magic { mask: 0x0002040810204000, shift: 58, offset: 0x0000000000000e08, nr: 0x0290908201004000 },
+	Magic { mask: 0x0020100804020000, shift: 59, offset: 0x0000000000000f23, nr: 0x0c041052c2040002 },
+	Magic { mask: 0x0040201008040000, shift: 59, offset: 0x0000000000000f39, nr: 0x440820a821010004 },
+	Magic { mask: 0x00004020100a0000, shift: 59, offset: 0x0000000000000f57, nr: 0x4180100420500c09 },
+	Magic { mask: 0x0000004022140000, shift: 59, offset: 0x0000000000000f76, nr: 0x8108210208809004 },
+	Magic { mask: 0x0000000244280000, shift: 59, offset: 0x0000000000000f92, nr: 0x0020018602040066 },
+	Magic { mask: 0x0000020408500000, shift: 59, offset: 0x0000000000000fb1, nr: 0xc428048140c00004 },
+	Magic { mask: 0x0002040810200000, shift: 59, offset: 0x0000000000000fca, nr: 0x9002110082608160 },
+	Magic { mask: 0x0004081020400000, shift: 59, offset: 0x0000000000000fe1, nr: 0x0040cc410090c01a },
+	Magic { mask: 0x0010080402000200, shift: 59, offset: 0x0000000000000fff, nr: 0x80a0004ca1005900 },
+	Magic { mask: 0x0020100804000400, shift: 59, offset: 0x000000000000101b, nr: 0x08440022840b1024 },
+	Magic { mask: 0x004020100a000a00, shift: 57, offset: 0x00000000000007ff, nr: 0x0268040408011804 },
+	Magic { mask: 0x0000402214001400, shift: 57, offset: 0x000000000000087d, nr: 0x0004000814200480 },
+	Magic { mask: 0x0000024428002800, shift: 57, offset: 0x00000000000008fc, nr: 0x080a001018040200 },
+	Magic { mask: 0x0002040850005000, shift: 57, offset: 0x000000000000097a, nr: 0x2028803238004008 },
+	Magic { mask: 0x0004081020002000, shift: 59, offset: 0x0000000000001033, nr: 0x0021001421180100 },
+	Magic { mask: 0x0008102040004000, shift: 59, offset: 0x000000000000104a, nr: 0x8101006884110080 },
+	Magic { mask: 0x0008040200020400, shift: 59, offset: 0x0000000000001065, nr: 0x6010080043082030 },
+	Magic { mask: 0x0010080400040800, shift: 59, offset: 0x0000000000001083, nr: 0x400a200023232044 },
+	Magic { mask: 0x0020100a000a1000, shift: 57, offset: 0x00000000000009f9, nr: 0x80080400180c3009 },
+	Magic { mask: 0x0040221400142200, shift: 55, offset: 0xffffffffffffffff, nr: 0x0121004004040001 },
+	Magic { mask: 0x0002442800284400, shift: 55, offset: 0x00000000000001ff, nr: 0x5114840200802000 },
+	Magic { mask: 0x0004085000500800, shift: 57, offset: 0x0000000000000a78, nr: 0x0804110010902004 },
+	Magic { mask: 0x0008102000201000, shift: 59, offset: 0x000000000000109c, nr: 0x2566008000c22010 },
+	Magic { mask: 0x0010204000402000, shift: 59, offset: 0x00000000000010b6, nr: 0x0862810002151009 },
+	Magic { mask: 0x0004020002040800, shift: 59, offset: 0x00000000000010d0, nr: 0x800800c305100400 },
+	Magic { mask: 0x0008040004081000, shift: 59, offset: 0x00000000000010ef, nr: 0x0243082008190044 },
+	Magic { mask: 0x00100a000a102000, shift: 57, offset: 0x0000000000000af7, nr: 0x80c0080800040040 },
+	Magic { mask: 0x0022140014224000, shift: 55, offset: 0x00000000000003ff, nr: 0x0201040400080211 },
+	Magic { mask: 0x0044280028440200, shift: 55, offset: 0x00000000000005ff, nr: 0x0090060201102008 },
+	Magic { mask: 0x0008500050080400, shift: 57, offset: 0x0000000000000b76, nr: 0x0880486500120100 },
+	Magic { mask: 0x0010200020100800, shift: 59, offset: 0x000000000000110c, nr: 0x0400441f08040100 },
+	Magic { mask: 0x0020400040201000, shift: 59, offset: 0x000000000000112a, nr: 0x08804c0951010102 },
+	Magic { mask: 0x0002000204081000, shift: 59, offset: 0x0000000000001140, nr: 0x6000348610340800 },
+	Magic { mask: 0x0004000408102000, shift: 59, offset: 0x000000000000115b, nr: 0x000013280ec00804 },
+	Magic { mask: 0x000a000a10204000, shift: 57, offset: 0x0000000000000bf5, nr: 0x4200031588029000 },
+	Magic { mask: 0x0014001422400000, shift: 57, offset: 0x0000000000000c70, nr: 0x000000300c100480 },
+	Magic { mask: 0x0028002844020000, shift: 57, offset: 0x0000000000000cef, nr: 0x00001c1004080c80 },
+	Magic { mask: 0x0050005008040200, shift: 57, offset: 0x0000000000000d6c, nr: 0x40a0001024803040 },
+	Magic { mask: 0x0020002010080400, shift: 59, offset: 0x0000000000001174, nr: 0x43e0020002201100 },
+	Magic { mask: 0x0040004020100800, shift: 59, offset: 0x0000000000001189, nr: 0x0000129121091040 },
+	Magic { mask: 0x0000020408102000, shift: 59, offset: 0x000000000000119e, nr: 0x0200060184108010 },
+	Magic { mask: 0x0000040810204000, shift: 59, offset: 0x00000000000011b3, nr: 0x2088008208c22502 },
+	Magic { mask: 0x00000a1020400000, shift: 59, offset: 0x00000000000011c5, nr: 0x2060a0085124400a },
+	Magic { mask: 0x0000142240000000, shift: 59, offset: 0x00000000000011df, nr: 0x0016080201038008 },
+	Magic { mask: 0x0000284402000000, shift: 59, offset: 0x00000000000011fb, nr: 0x0282084000618210 },
+	Magic { mask: 0x0000500804020000, shift: 59, offset: 0x0000000000001219, nr: 0x002890600080e351 },
+	Magic { mask: 0x0000201008040200, shift: 59, offset: 0x000000000000122b, nr: 0x0000022942209a00 },
+	Magic { mask: 0x0000402010080400, shift: 59, offset: 0x0000000000001245, nr: 0x0020004125483200 },
+	Magic { mask: 0x0002040810204000, shift: 58, offset: 0x0000000000000e2d, nr: 0x1304004108011002 },
+	Magic { mask: 0x0004081020400000, shift: 59, offset: 0x0000000000001258, nr: 0x0400000085014840 },
+	Magic { mask: 0x000a102040000000, shift: 59, offset: 0x0000000000001275, nr: 0x000810900892d008 },
+	Magic { mask: 0x0014224000000000, shift: 59, offset: 0x0000000000001292, nr: 0x0103404202020704 },
+	Magic { mask: 0x0028440200000000, shift: 59, offset: 0x00000000000012b0, nr: 0x01000000400081c2 },
+	Magic { mask: 0x0050080402000000, shift: 59, offset: 0x00000000000012c6, nr: 0x00000002048c20a0 },
+	Magic { mask: 0x0020100804020000, shift: 59, offset: 0x00000000000012e0, nr: 0x1002020441480504 },
+	Magic { mask: 0x0040201008040200, shift: 58, offset: 0x0000000000000e5e, nr: 0x4800040184005c81 },
+];
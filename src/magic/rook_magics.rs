@@ -0,0 +1,73 @@
+// Generated by: magic rook --overlap --candidates 256 --out src/magic/rook_magics.rs
+
+use crate::magic::Magic;
+
+pub const ROOK_TABLE_SIZE: usize = 101621;
+
+#[rustfmt::skip]
+pub const ROOK_MAGICS: [Magic; 64] = [
+	Magic { mask: 0x000101010101017e, shift: 52, offset: 0xfffffffffffffe68, nr: 0x0030008600180002 },
+	Magic { mask: 0x000202020202027c, shift: 53, offset: 0x0000000000003e3d, nr: 0x1400420400208100 },
+	Magic { mask: 0x000404040404047a, shift: 53, offset: 0x000000000000463d, nr: 0x01000d0004200841 },
+	Magic { mask: 0x0008080808080876, shift: 53, offset: 0x0000000000004e3b, nr: 0x19800802b0000180 },
+	Magic { mask: 0x001010101010106e, shift: 53, offset: 0x000000000000563a, nr: 0x0900100300010800 },
+	Magic { mask: 0x002020202020205e, shift: 53, offset: 0x0000000000005e3a, nr: 0x0200041200102108 },
+	Magic { mask: 0x004040404040403e, shift: 53, offset: 0x0000000000006638, nr: 0x04000800a1c40030 },
+	Magic { mask: 0x008080808080807e, shift: 52, offset: 0x0000000000000e60, nr: 0x01000128a2001900 },
+	Magic { mask: 0x0001010101017e00, shift: 53, offset: 0x0000000000006e37, nr: 0x0000800198c00008 },
+	Magic { mask: 0x0002020202027c00, shift: 54, offset: 0x000000000000fdd5, nr: 0x10ab200021090001 },
+	Magic { mask: 0x0004040404047a00, shift: 54, offset: 0x00000000000101d2, nr: 0x49040081004400a1 },
+	Magic { mask: 0x0008080808087600, shift: 54, offset: 0x000000000001059d, nr: 0x0200a00a0a20a004 },
+	Magic { mask: 0x0010101010106e00, shift: 54, offset: 0x0000000000010992, nr: 0x0002000906000120 },
+	Magic { mask: 0x0020202020205e00, shift: 54, offset: 0x0000000000010d91, nr: 0x0501000904000100 },
+	Magic { mask: 0x0040404040403e00, shift: 54, offset: 0x0000000000011190, nr: 0x0282000882000084 },
+	Magic { mask: 0x0080808080807e00, shift: 53, offset: 0x0000000000007634, nr: 0x0c8a000114840022 },
+	Magic { mask: 0x00010101017e0100, shift: 53, offset: 0x0000000000007e33, nr: 0xa001808011400010 },
+	Magic { mask: 0x00020202027c0200, shift: 54, offset: 0x000000000001158e, nr: 0x0840022010001800 },
+	Magic { mask: 0x00040404047a0400, shift: 54, offset: 0x000000000001198e, nr: 0x0000820022021140 },
+	Magic { mask: 0x0008080808760800, shift: 54, offset: 0x0000000000011d8e, nr: 0x9801420022000910 },
+	Magic { mask: 0x00101010106e1000, shift: 54, offset: 0x000000000001218e, nr: 0xb000808008010401 },
+	Magic { mask: 0x00202020205e2000, shift: 54, offset: 0x000000000001258d, nr: 0x0000060003120001 },
+	Magic { mask: 0x00404040403e4000, shift: 54, offset: 0x000000000001298d, nr: 0x0008040008010e10 },
+	Magic { mask: 0x00808080807e8000, shift: 53, offset: 0x0000000000008632, nr: 0x40092200004c0025 },
+	Magic { mask: 0x000101017e010100, shift: 53, offset: 0x0000000000008e32, nr: 0x0200400080042290 },
+	Magic { mask: 0x000202027c020200, shift: 54, offset: 0x0000000000012d8d, nr: 0x00c0200040401000 },
+	Magic { mask: 0x000404047a040400, shift: 54, offset: 0x000000000001318d, nr: 0x0048410100200010 },
+	Magic { mask: 0x0008080876080800, shift: 54, offset: 0x000000000001358c, nr: 0x2000030500100020 },
+	Magic { mask: 0x001010106e101000, shift: 54, offset: 0x0000000000013983, nr: 0x9400030100100801 },
+	Magic { mask: 0x002020205e202000, shift: 54, offset: 0x0000000000013d72, nr: 0x3000010100040048 },
+	Magic { mask: 0x004040403e404000, shift: 54, offset: 0x0000000000014172, nr: 0x0021000100044200 },
+	Magic { mask: 0x008080807e808000, shift: 53, offset: 0x0000000000009632, nr: 0x1400800880004100 },
+	Magic { mask: 0x0001017e01010100, shift: 53, offset: 0x0000000000009e32, nr: 0x0010204000800080 },
+	Magic { mask: 0x0002027c02020200, shift: 54, offset: 0x0000000000014571, nr: 0x000010080c2000a0 },
+	Magic { mask: 0x0004047a04040400, shift: 54, offset: 0x0000000000014971, nr: 0x1880401101002001 },
+	Magic { mask: 0x0008087608080800, shift: 54, offset: 0x0000000000014d71, nr: 0x8040044202001060 },
+	Magic { mask: 0x0010106e10101000, shift: 54, offset: 0x000000000001516b, nr: 0x1200100103000800 },
+	Magic { mask: 0x0020205e20202000, shift: 54, offset: 0x000000000001556b, nr: 0x0441080101000400 },
+	Magic { mask: 0x0040403e40404000, shift: 54, offset: 0x000000000001596a, nr: 0x4100800100800200 },
+	Magic { mask: 0x0080807e80808000, shift: 53, offset: 0x000000000000a630, nr: 0x0080002054900300 },
+	Magic { mask: 0x00017e0101010100, shift: 53, offset: 0x000000000000ae2d, nr: 0x8000104010848002 },
+	Magic { mask: 0x00027c0202020200, shift: 54, offset: 0x0000000000015d6a, nr: 0x0840000408141001 },
+	Magic { mask: 0x00047a0404040400, shift: 54, offset: 0x000000000001615f, nr: 0x00000260120c2001 },
+	Magic { mask: 0x0008760808080800, shift: 54, offset: 0x000000000001655e, nr: 0x00004006005a0010 },
+	Magic { mask: 0x00106e1010101000, shift: 54, offset: 0x000000000001695d, nr: 0x024002002806000c },
+	Magic { mask: 0x00205e2020202000, shift: 54, offset: 0x0000000000016d59, nr: 0x8100010004010008 },
+	Magic { mask: 0x00403e4040404000, shift: 54, offset: 0x000000000001714f, nr: 0x021200004801a010 },
+	Magic { mask: 0x00807e8080808000, shift: 53, offset: 0x000000000000b62b, nr: 0x2920240028aa0001 },
+	Magic { mask: 0x007e010101010100, shift: 53, offset: 0x000000000000be22, nr: 0x0800018840847200 },
+	Magic { mask: 0x007c020202020200, shift: 54, offset: 0x0000000000017533, nr: 0x0000084422108200 },
+	Magic { mask: 0x007a040404040400, shift: 54, offset: 0x0000000000017928, nr: 0x0000018428405200 },
+	Magic { mask: 0x0076080808080800, shift: 54, offset: 0x0000000000017d23, nr: 0x1090000411002100 },
+	Magic { mask: 0x006e101010101000, shift: 54, offset: 0x000000000001811f, nr: 0x4260024600243200 },
+	Magic { mask: 0x005e202020202000, shift: 54, offset: 0x000000000001851d, nr: 0x0246000083106600 },
+	Magic { mask: 0x003e404040404000, shift: 54, offset: 0x00000000000188f5, nr: 0x0008002042000180 },
+	Magic { mask: 0x007e808080808000, shift: 53, offset: 0x000000000000c61a, nr: 0x000200102800a4a0 },
+	Magic { mask: 0x7e01010101010100, shift: 52, offset: 0x0000000000001e49, nr: 0x0000048020984102 },
+	Magic { mask: 0x7c02020202020200, shift: 53, offset: 0x000000000000ce03, nr: 0x0000018084441422 },
+	Magic { mask: 0x7a04040404040400, shift: 53, offset: 0x000000000000d5ee, nr: 0x4100041081401822 },
+	Magic { mask: 0x7608080808080800, shift: 53, offset: 0x000000000000dde1, nr: 0x000200011084480a },
+	Magic { mask: 0x6e10101010101000, shift: 53, offset: 0x000000000000e5dd, nr: 0x802000828a000522 },
+	Magic { mask: 0x5e20202020202000, shift: 53, offset: 0x000000000000edd9, nr: 0x0001000041a40037 },
+	Magic { mask: 0x3e40404040404000, shift: 53, offset: 0x000000000000f5d8, nr: 0x004000281004c104 },
+	Magic { mask: 0x7e80808080808000, shift: 52, offset: 0x0000000000002e40, nr: 0x1004040001c19302 },
+];
@@ -0,0 +1,283 @@
+use std::cmp::Reverse;
+
+use super::{Magic, BISHOP_MAGICS, BISHOP_TABLE_SIZE, ROOK_MAGICS, ROOK_TABLE_SIZE};
+
+use crate::{
+	bitboard::Bitboard, error::Error, move_gen::MoveGenerator, notation::Notation, piece::Piece,
+	zobrist::splitmix64,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MagicOptions {
+	pub seed: u64,
+	// Index bits shared by every square, None uses the bit count of each mask
+	pub fixed_bits: Option<u32>,
+	// Lets squares share slots that are unused or hold the same attacks
+	pub overlap: bool,
+	// Valid numbers tried per square when overlapping, the one growing the table least is kept
+	pub candidates: usize,
+}
+
+pub struct MagicTable {
+	pub piece: Piece,
+	pub magics: [Magic; 64],
+	pub size: usize,
+}
+
+fn slider_mask(piece: Piece, square_index: u8) -> Bitboard {
+	let ok = piece == Piece::Rook || piece == Piece::Bishop;
+	assert!(ok, "Illegal piece: {}", piece.to_full_name());
+
+	if piece == Piece::Rook {
+		MoveGenerator::rook_mask(square_index)
+	} else {
+		MoveGenerator::bishop_mask(square_index)
+	}
+}
+
+fn slider_boards(piece: Piece, square_index: u8) -> (Bitboard, Vec<Bitboard>, Vec<Bitboard>) {
+	let mask = slider_mask(piece, square_index);
+
+	let blocker_boards = MoveGenerator::blocker_boards(mask);
+	let attack_boards = if piece == Piece::Rook {
+		MoveGenerator::rook_attack_boards(square_index, &blocker_boards)
+	} else {
+		MoveGenerator::bishop_attack_boards(square_index, &blocker_boards)
+	};
+
+	(mask, blocker_boards, attack_boards)
+}
+
+// SplitMix64 gives the same numbers on every platform and version, unlike rand's StdRng
+fn random_u64_fewbits(state: &mut u64) -> u64 {
+	splitmix64(state) & splitmix64(state) & splitmix64(state)
+}
+
+// Used slots of one square as (index, attacks)
+type UsedSlots = Vec<(usize, Bitboard)>;
+
+// Slots of one try, stamped with the try so they don't have to be cleared between numbers
+struct Slots {
+	stamps: Vec<u32>,
+	attacks: Vec<Bitboard>,
+	stamp: u32,
+}
+
+impl Slots {
+	fn new() -> Self {
+		Self {
+			stamps: vec![0; 1 << 20],
+			attacks: vec![0; 1 << 20],
+			stamp: 0,
+		}
+	}
+
+	// None when two different attacks collide
+	fn fill(
+		&mut self,
+		magic: &Magic,
+		blockers: &[Bitboard],
+		attacks: &[Bitboard],
+	) -> Option<UsedSlots> {
+		self.stamp += 1;
+
+		let mut used = Vec::new();
+
+		for (blocker, &attack) in blockers.iter().zip(attacks) {
+			let index = magic.get_index(*blocker);
+
+			if self.stamps[index] != self.stamp {
+				self.stamps[index] = self.stamp;
+				self.attacks[index] = attack;
+				used.push(index);
+			} else if self.attacks[index] != attack {
+				return None;
+			}
+		}
+
+		used.sort_unstable();
+
+		Some(
+			used.into_iter()
+				.map(|index| (index, self.attacks[index]))
+				.collect(),
+		)
+	}
+}
+
+// First fit: the lowest start, possibly before 0, where every used slot lands on a hole or on
+// the same attacks. Slots past the end of the table always fit
+fn find_offset(table: &[Bitboard], used: &[(usize, Bitboard)]) -> i64 {
+	let first = used[0].0 as i64;
+
+	(-first..=table.len() as i64)
+		.find(|&start| {
+			used.iter().all(|&(index, attack)| {
+				table
+					.get((start + index as i64) as usize)
+					.is_none_or(|&entry| entry == 0 || entry == attack)
+			})
+		})
+		.unwrap()
+}
+
+impl MagicTable {
+	// The tables the move generator is built with
+	pub fn current(piece: Piece) -> Self {
+		let (magics, size) = if piece == Piece::Rook {
+			(ROOK_MAGICS, ROOK_TABLE_SIZE)
+		} else {
+			(BISHOP_MAGICS, BISHOP_TABLE_SIZE)
+		};
+
+		Self {
+			piece,
+			magics,
+			size,
+		}
+	}
+
+	// The same seed and options always give the same table
+	pub fn search(piece: Piece, options: &MagicOptions) -> Result<Self, Error> {
+		let mut state = options.seed;
+
+		let mut table: Vec<Bitboard> = Vec::new();
+		let mut slots = Slots::new();
+		let mut magics = [Magic::default(); 64];
+
+		let candidates = if options.overlap {
+			options.candidates.max(1)
+		} else {
+			1
+		};
+
+		//* Big squares first, the small ones fill the holes they leave */
+		let mut squares: Vec<usize> = (0..64).collect();
+
+		if options.overlap {
+			squares.sort_by_key(|&square_index| {
+				Reverse(slider_mask(piece, square_index as u8).count_ones())
+			});
+		}
+
+		for square_index in squares {
+			let (mask, blockers, attacks) = slider_boards(piece, square_index as u8);
+			let bits = options.fixed_bits.unwrap_or(mask.count_ones());
+
+			if bits < mask.count_ones() || bits > 20 {
+				return Err(Error::InvalidMagicBits {
+					square: Notation::from(square_index).to_string(),
+					bits,
+				});
+			}
+
+			//* Number, start in the table, slots and table length once placed */
+			let mut best: Option<(u64, i64, UsedSlots, usize)> = None;
+			let mut found = 0;
+
+			while found < candidates {
+				let nr = random_u64_fewbits(&mut state);
+
+				let magic = Magic {
+					mask,
+					shift: (64 - bits) as u8,
+					offset: 0,
+					nr,
+				};
+
+				let Some(used) = slots.fill(&magic, &blockers, &attacks) else {
+					continue;
+				};
+
+				found += 1;
+
+				let (start, length) = if options.overlap {
+					let start = find_offset(&table, &used);
+					let end = start + used[used.len() - 1].0 as i64 + 1;
+
+					(start, table.len().max(end as usize))
+				} else {
+					(table.len() as i64, table.len() + (1 << bits))
+				};
+
+				//* The number whose used slots fit the holes best, so the table grows least */
+				let is_better = best
+					.as_ref()
+					.is_none_or(|&(_, _, _, best_length)| length < best_length);
+
+				if is_better {
+					best = Some((nr, start, used, length));
+				}
+			}
+
+			let (nr, start, used, length) = best.unwrap();
+
+			table.resize(length, 0);
+
+			for (index, attack) in used {
+				table[(start + index as i64) as usize] = attack;
+			}
+
+			magics[square_index] = Magic {
+				mask,
+				shift: (64 - bits) as u8,
+				offset: start as u64,
+				nr,
+			};
+		}
+
+		Ok(Self {
+			piece,
+			magics,
+			size: table.len(),
+		})
+	}
+
+	// Every blocker board has to land inside the table, on a slot holding its own attacks
+	pub fn verify(&self) -> Result<(), Error> {
+		let mut table = vec![0; self.size];
+
+		for (square_index, magic) in self.magics.iter().enumerate() {
+			let (_, blockers, attacks) = slider_boards(self.piece, square_index as u8);
+
+			for (blocker, attack) in blockers.iter().zip(attacks) {
+				let index = magic.get_index(*blocker);
+
+				if index >= self.size || table[index] != 0 && table[index] != attack {
+					return Err(Error::InvalidMagic {
+						square: Notation::from(square_index).to_string(),
+					});
+				}
+
+				table[index] = attack;
+			}
+		}
+
+		Ok(())
+	}
+
+	// Drop-in replacement for src/magic/rook_magics.rs or bishop_magics.rs
+	pub fn to_rust_source(&self) -> String {
+		let name = self.piece.to_full_name().to_uppercase();
+
+		let mut source = String::new();
+
+		source.push_str("use crate::magic::Magic;\n\n");
+		source.push_str(&format!(
+			"pub const {name}_TABLE_SIZE: usize = {};\n\n",
+			self.size
+		));
+		source.push_str("#[rustfmt::skip]\n");
+		source.push_str(&format!("pub const {name}_MAGICS: [Magic; 64] = [\n"));
+
+		for magic in self.magics {
+			source.push_str(&format!(
+				"\tMagic {{ mask: {:#018x}, shift: {}, offset: {:#018x}, nr: {:#018x} }},\n",
+				magic.mask, magic.shift, magic.offset, magic.nr
+			));
+		}
+
+		source.push_str("];\n");
+		source
+	}
+}
@@ -1,188 +1,411 @@
 mod attack;
+mod check;
+pub mod context;
 pub mod defs;
 mod extra;
 mod init;
+mod kogge_stone;
+mod legal;
+#[cfg(any(not(feature = "kogge-stone-only"), feature = "magic-search"))]
 mod mask;
+pub mod move_list;
 mod num_to_edge;
-
-use self::defs::Move;
+#[cfg(all(
+	feature = "pext",
+	target_arch = "x86_64",
+	not(feature = "kogge-stone-only")
+))]
+mod pext;
+mod san;
+mod see;
+mod tables;
+
+use self::{
+	context::AttackContext,
+	defs::{Move, MoveKind, SliderBackend},
+	move_list::MoveList,
+	tables::{tables, Tables},
+};
 
 use crate::{
-	bitboard::{get_lsb_index, pop_lsb, set_bit, Bitboard},
+	bitboard::{get_lsb_index, pop_lsb, Bitboard},
 	board::Board,
-	magic::Magic,
-	piece::Piece,
+	error::Error,
+	game_status::GameStatus,
+	notation::Notation,
+	piece::{Piece, PROMOTION_PIECES},
 };
 
 pub const PAWN_PUSH_DIRECTION: [i8; 2] = [8, -8];
 pub const CASTLE_KING_INDEX: [u8; 4] = [02, 06, 58, 62];
+pub const CASTLE_ROOK_TARGET: [u8; 4] = [03, 05, 59, 61];
 
 const PAWN_DOUBLE_PUSH_RANK: [Bitboard; 2] = [0x00000000ff000000, 0x000000ff00000000];
+const PAWN_PROMOTION_RANK: [Bitboard; 2] = [0xff00000000000000, 0x00000000000000ff];
 
 const CASTLE_RIGHTS: [usize; 2] = [0b0011, 0b1100];
-const CASTLE_EMPTY: [Bitboard; 4] = [
-	0x000000000000000e,
-	0x0000000000000060,
-	0x0e00000000000000,
-	0x6000000000000000,
-];
-
-const CASTLE_ATTACK: [Bitboard; 4] = [
-	0x000000000000000c,
-	0x0000000000000060,
-	0x0c00000000000000,
-	0x6000000000000000,
-];
 
+// Every square from one square to the other on the same rank, both included
+fn rank_span(square_a: u8, square_b: u8) -> Bitboard {
+	let low = u8::min(square_a, square_b);
+	let high = u8::max(square_a, square_b);
+
+	(u64::MAX << low) & (u64::MAX >> (63 - high))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Stage {
+	All,
+	Captures,
+	Quiets,
+	Evasions,
+}
+
+// The tables are shared, so a MoveGenerator is free to create, copy and send between threads
+#[derive(Clone, Copy)]
 pub struct MoveGenerator {
-	king: [Bitboard; 64],
-	pawn: [[Bitboard; 64]; 2],
-	knight: [Bitboard; 64],
-	rook: Vec<Bitboard>,
-	bishop: Vec<Bitboard>,
-	rook_magics: [Magic; 64],
-	bishop_magics: [Magic; 64],
-
-	square_bit: [u64; 64],
-
-	check: bool,
-	double_check: bool,
-
-	pub pin_rays: [Bitboard; 64],
-	pub check_ray: Bitboard,
-	pub bb_attack: Bitboard,
+	tables: &'static Tables,
+	backend: SliderBackend,
 }
 
 impl Default for MoveGenerator {
 	fn default() -> Self {
-		let mut move_gen = Self {
-			king: [0; 64],
-			pawn: [[0; 64]; 2],
-			knight: [0; 64],
-			rook: vec![0; 102_400],
-			bishop: vec![0; 5_248],
-			rook_magics: [Magic::default(); 64],
-			bishop_magics: [Magic::default(); 64],
-
-			square_bit: [0; 64],
-
-			check: false,
-			double_check: false,
-
-			pin_rays: [0; 64],
-			check_ray: 0,
-			bb_attack: 0,
-		};
-		move_gen.init();
-
-		move_gen
+		Self {
+			tables: tables(),
+			backend: SliderBackend::detect(),
+		}
 	}
 }
 
 impl MoveGenerator {
-	pub fn all_possible(&mut self, board: &Board) -> Vec<Move> {
-		let mut move_list = Vec::new();
+	pub fn with_backend(backend: SliderBackend) -> Result<Self, Error> {
+		if !backend.is_supported() {
+			return Err(Error::UnsupportedBackend {
+				backend: format!("{backend:?}"),
+			});
+		}
+
+		Ok(Self {
+			tables: tables(),
+			backend,
+		})
+	}
+
+	pub fn backend(&self) -> SliderBackend {
+		self.backend
+	}
+
+	pub fn all_possible(&self, board: &Board) -> MoveList {
+		let context = self.calculate_attack_mask(board);
+
+		self.generate(board, &context, Stage::All)
+	}
+
+	// Captures, en passant and every promotion, including quiet ones
+	pub fn captures(&self, board: &Board) -> MoveList {
+		let context = self.calculate_attack_mask(board);
 
-		self.calculate_attack_mask(&board);
+		self.generate(board, &context, Stage::Captures)
+	}
 
-		self.piece(&board, Piece::King, &mut move_list);
-		self.castling(&board, &mut move_list);
+	// Everything captures leaves out, castling included
+	pub fn quiets(&self, board: &Board) -> MoveList {
+		let context = self.calculate_attack_mask(board);
+
+		self.generate(board, &context, Stage::Quiets)
+	}
 
-		if self.double_check {
+	// Every legal move out of check, or nothing when not in check
+	pub fn evasions(&self, board: &Board) -> MoveList {
+		let context = self.calculate_attack_mask(board);
+
+		if !context.check {
+			return MoveList::new();
+		}
+
+		self.generate(board, &context, Stage::Evasions)
+	}
+
+	fn generate(&self, board: &Board, context: &AttackContext, stage: Stage) -> MoveList {
+		let mut move_list = MoveList::new();
+
+		let color = board.get_color();
+
+		let bb_empty = !board.get_occupancy();
+		let bb_opponent_pieces = board.get_allys(!color);
+		let promotion_rank = PAWN_PROMOTION_RANK[color.to_index()];
+
+		let (bb_targets, bb_pawn_targets) = match stage {
+			Stage::All => (Bitboard::MAX, Bitboard::MAX),
+			Stage::Captures => (bb_opponent_pieces, bb_opponent_pieces | promotion_rank),
+			Stage::Quiets => (bb_empty, bb_empty & !promotion_rank),
+			//* Anything but the king has to capture the checker or block its ray */
+			Stage::Evasions => (context.check_ray, context.check_ray),
+		};
+
+		if stage == Stage::Evasions {
+			self.piece(board, context, Piece::King, Bitboard::MAX, &mut move_list);
+		} else {
+			self.piece(board, context, Piece::King, bb_targets, &mut move_list);
+		}
+
+		if stage == Stage::All || stage == Stage::Quiets {
+			self.castling(board, context, &mut move_list);
+		}
+
+		if context.double_check {
 			return move_list;
 		}
 
-		self.pawns(&board, &mut move_list);
-		self.enpassant(&board, &mut move_list);
+		self.pawns(board, context, bb_pawn_targets, &mut move_list);
+
+		if stage != Stage::Quiets {
+			self.enpassant(board, context, &mut move_list);
+		}
 
-		self.piece(&board, Piece::Knight, &mut move_list);
-		self.piece(&board, Piece::Bishop, &mut move_list);
-		self.piece(&board, Piece::Rook, &mut move_list);
-		self.piece(&board, Piece::Queen, &mut move_list);
+		self.piece(board, context, Piece::Knight, bb_targets, &mut move_list);
+		self.piece(board, context, Piece::Bishop, bb_targets, &mut move_list);
+		self.piece(board, context, Piece::Rook, bb_targets, &mut move_list);
+		self.piece(board, context, Piece::Queen, bb_targets, &mut move_list);
 
 		move_list
 	}
 
-	pub fn piece(&self, board: &Board, piece: Piece, list: &mut Vec<Move>) {
+	// Same as all_possible().len(), counted from the target bitboards without building any move
+	pub fn count_legal(&self, board: &Board) -> usize {
+		let context = self.calculate_attack_mask(board);
 		let color = board.get_color();
 
-		let bb_occupancy = board.get_occupancy();
-		let bb_ally_pieces = board.get_allys(color);
-		let mut bb_pieces = board.get_bitboard(piece, color);
+		let mut count = 0;
 
-		let is_king = piece == Piece::King;
+		let king_square = get_lsb_index(board.get_bitboard(Piece::King, color)) as usize;
+		count += self
+			.piece_moves(board, &context, Piece::King, king_square)
+			.count_ones();
 
-		while bb_pieces > 0 {
-			let square_index = pop_lsb(&mut bb_pieces) as usize;
-			let bb_target = match piece {
-				Piece::King | Piece::Knight => self.get_non_slider_attacks(piece, square_index),
-				Piece::Queen | Piece::Rook | Piece::Bishop => {
-					self.get_slider_attacks(piece, square_index, bb_occupancy)
+		count += self.castle_indices(board, &context).count_ones();
+
+		if !context.double_check {
+			let promotion_rank = PAWN_PROMOTION_RANK[color.to_index()];
+			let mut bb_ally_pawns = board.get_bitboard(Piece::Pawn, color);
+
+			while bb_ally_pawns > 0 {
+				let square_index = pop_lsb(&mut bb_ally_pawns) as usize;
+				let bb_moves = self.pawn_moves(board, &context, square_index);
+
+				count += (bb_moves & !promotion_rank).count_ones();
+				count += (bb_moves & promotion_rank).count_ones() * PROMOTION_PIECES.len() as u32;
+			}
+
+			count += self.enpassant_pawns(board, &context).count_ones();
+
+			for piece in [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen] {
+				let mut bb_pieces = board.get_bitboard(piece, color);
+
+				while bb_pieces > 0 {
+					let square_index = pop_lsb(&mut bb_pieces) as usize;
+					count += self
+						.piece_moves(board, &context, piece, square_index)
+						.count_ones();
 				}
-				_ => panic!("Not a piece: {}", piece.to_full_name()),
-			} & !bb_ally_pieces;
+			}
+		}
 
-			let mut bb_moves = bb_target;
+		count as usize
+	}
+
+	pub fn game_status(&self, board: &Board) -> GameStatus {
+		let context = self.calculate_attack_mask(board);
 
-			if is_king {
-				bb_moves &= !self.bb_attack;
+		if self.generate(board, &context, Stage::All).is_empty() {
+			return if context.check {
+				GameStatus::Checkmate
 			} else {
-				self.isolate_attack_pin_checks(&mut bb_moves, square_index);
+				GameStatus::Stalemate
+			};
+		}
+
+		if board.is_fivefold() {
+			GameStatus::FivefoldRepetition
+		} else if board.halfmove_clock() >= 150 {
+			GameStatus::SeventyFiveMoveRule
+		} else if board.is_insufficient_material() {
+			GameStatus::InsufficientMaterial
+		} else if board.is_threefold() {
+			GameStatus::ThreefoldRepetition
+		} else if board.halfmove_clock() >= 100 {
+			GameStatus::FiftyMoveRule
+		} else {
+			GameStatus::Ongoing
+		}
+	}
+
+	pub fn parse_uci(&self, board: &Board, uci: &str) -> Result<Move, Error> {
+		let invalid = || Error::InvalidUciMove {
+			uci: uci.to_string(),
+		};
+
+		let value = uci.trim().to_ascii_lowercase();
+
+		if !value.is_ascii() || !(4..=5).contains(&value.len()) {
+			return Err(invalid());
+		}
+
+		let start_square = value[0..2].parse::<Notation>().map_err(|_| invalid())?;
+		let target_square = value[2..4].parse::<Notation>().map_err(|_| invalid())?;
+		let promotion = match value[4..].chars().next() {
+			Some(char) if "nbrq".contains(char) => Some(Piece::from(char)),
+			Some(_) => return Err(invalid()),
+			None => None,
+		};
+
+		let found = self.all_possible(board).into_iter().find(|to_move| {
+			if to_move.from() != start_square || to_move.promotion() != promotion {
+				return false;
+			}
+
+			// Castling is accepted both as the king's move and as the king taking its own rook
+			if to_move.kind() == MoveKind::Castle {
+				let rook_square = board.get_castle_rook(to_move.get_castle_index());
+
+				if target_square.to_index() == rook_square as usize {
+					return true;
+				}
+
+				if board.is_chess960() {
+					return false;
+				}
+			}
+
+			to_move.to() == target_square
+		});
+
+		found.ok_or_else(|| Error::IllegalMove {
+			notation: uci.to_string(),
+		})
+	}
+
+	pub(crate) fn piece(
+		&self,
+		board: &Board,
+		context: &AttackContext,
+		piece: Piece,
+		bb_targets: Bitboard,
+		list: &mut MoveList,
+	) {
+		let mut bb_pieces = board.get_bitboard(piece, board.get_color());
+
+		while bb_pieces > 0 {
+			let square_index = pop_lsb(&mut bb_pieces) as usize;
+			let bb_moves = self.piece_moves(board, context, piece, square_index) & bb_targets;
+
+			if bb_moves > 0 {
+				self.add_move(board, piece, square_index as u8, bb_moves, list);
+			}
+		}
+	}
+
+	// Legal target squares of a non pawn piece
+	fn piece_moves(
+		&self,
+		board: &Board,
+		context: &AttackContext,
+		piece: Piece,
+		square_index: usize,
+	) -> Bitboard {
+		let bb_ally_pieces = board.get_allys(board.get_color());
+
+		let mut bb_moves = match piece {
+			Piece::King | Piece::Knight => self.get_non_slider_attacks(piece, square_index),
+			Piece::Queen | Piece::Rook | Piece::Bishop => {
+				self.get_slider_attacks(piece, square_index, board.get_occupancy())
 			}
+			_ => panic!("Not a piece: {}", piece.to_full_name()),
+		} & !bb_ally_pieces;
+
+		if piece == Piece::King {
+			bb_moves &= !context.bb_attack;
+		} else {
+			context.isolate_attack_pin_checks(&mut bb_moves, square_index);
+		}
+
+		bb_moves
+	}
+
+	pub(crate) fn pawns(
+		&self,
+		board: &Board,
+		context: &AttackContext,
+		bb_targets: Bitboard,
+		list: &mut MoveList,
+	) {
+		let mut bb_ally_pawns = board.get_bitboard(Piece::Pawn, board.get_color());
+
+		while bb_ally_pawns > 0 {
+			let square_index = pop_lsb(&mut bb_ally_pawns) as usize;
+			let bb_moves = self.pawn_moves(board, context, square_index) & bb_targets;
 
 			if bb_moves > 0 {
-				self.add_move(&board, piece, square_index as u8, bb_moves, list);
+				self.add_move(board, Piece::Pawn, square_index as u8, bb_moves, list);
 			}
 		}
 	}
 
-	pub fn pawns(&self, board: &Board, list: &mut Vec<Move>) {
+	// Legal target squares of a pawn, en passant excluded
+	fn pawn_moves(&self, board: &Board, context: &AttackContext, square_index: usize) -> Bitboard {
 		let color = board.get_color();
 		let inactive = !color;
 
 		let bb_empty = !board.get_occupancy();
-
 		let bb_opponent_pieces = board.get_allys(inactive);
-		let mut bb_ally_pawns = board.get_bitboard(Piece::Pawn, color);
 
 		let color_index = color.to_index();
 		let double_push_rank = PAWN_DOUBLE_PUSH_RANK[color_index];
 		let direction = PAWN_PUSH_DIRECTION[color_index];
 		let rotation_count = (64 + direction) as u32;
 
-		while bb_ally_pawns > 0 {
-			let square_index = pop_lsb(&mut bb_ally_pawns) as usize;
-			let mut bb_moves = 0;
+		let mut bb_moves = 0;
 
-			//* Pushs */
-			{
-				let bb_push = self.square_bit[(square_index as i8 + direction) as usize];
-				let bb_one_step = bb_push & bb_empty;
-				let bb_two_step =
-					bb_one_step.rotate_left(rotation_count) & bb_empty & double_push_rank;
+		//* Pushs */
+		{
+			let bb_push = self.tables.square_bit[(square_index as i8 + direction) as usize];
+			let bb_one_step = bb_push & bb_empty;
+			let bb_two_step = bb_one_step.rotate_left(rotation_count) & bb_empty & double_push_rank;
 
-				bb_moves |= bb_one_step | bb_two_step;
-			}
+			bb_moves |= bb_one_step | bb_two_step;
+		}
 
-			//* Captures */
-			{
-				let capture_mask = self.get_pawn_attacks(square_index, color);
-				let bb_capture = capture_mask & bb_opponent_pieces;
+		//* Captures */
+		{
+			let capture_mask = self.get_pawn_attacks(square_index, color);
+			let bb_capture = capture_mask & bb_opponent_pieces;
 
-				bb_moves |= bb_capture;
-			}
+			bb_moves |= bb_capture;
+		}
 
-			self.isolate_attack_pin_checks(&mut bb_moves, square_index);
+		context.isolate_attack_pin_checks(&mut bb_moves, square_index);
 
-			if bb_moves > 0 {
-				self.add_move(&board, Piece::Pawn, square_index as u8, bb_moves, list);
+		bb_moves
+	}
+
+	pub(crate) fn enpassant(&self, board: &Board, context: &AttackContext, list: &mut MoveList) {
+		let mut bb_pawns = self.enpassant_pawns(board, context);
+
+		if let Some(target_square) = board.get_enpassant() {
+			let bb_moves = self.tables.square_bit[target_square];
+
+			while bb_pawns > 0 {
+				let square_index = pop_lsb(&mut bb_pawns);
+
+				self.add_move(board, Piece::Pawn, square_index, bb_moves, list);
 			}
 		}
 	}
 
-	pub fn enpassant(&self, board: &Board, list: &mut Vec<Move>) {
-		if board.enpassant == None {
-			return;
+	// Pawns that can legally take en passant
+	fn enpassant_pawns(&self, board: &Board, context: &AttackContext) -> Bitboard {
+		if board.get_enpassant().is_none() {
+			return 0;
 		}
 
 		let color = board.get_color();
@@ -199,8 +422,8 @@ impl MoveGenerator {
 		let enpassant_pin_rank = PAWN_DOUBLE_PUSH_RANK[inactive.to_index()];
 		let bb_enpassant_pieces = bb_ally_king | bb_opp_rooks | bb_opp_queens;
 
-		let target_square = board.enpassant.unwrap();
-		let bb_moves = self.square_bit[target_square];
+		let target_square = board.get_enpassant().unwrap();
+		let bb_moves = self.tables.square_bit[target_square];
 
 		let mut bb_pawns = bb_ally_pawns & self.get_pawn_attacks(target_square, inactive);
 
@@ -228,62 +451,97 @@ impl MoveGenerator {
 				}
 			}
 
-			if self.check {
+			if context.check {
 				let pawn_square = target_square as i8 - PAWN_PUSH_DIRECTION[color.to_index()];
-				let pawn_bit = self.square_bit[pawn_square as usize];
+				let pawn_bit = self.tables.square_bit[pawn_square as usize];
 
-				if pawn_bit & self.check_ray == 0 {
+				if pawn_bit & context.check_ray == 0 {
 					bb_pawns = 0;
 				}
 			}
 
-			while bb_pawns > 0 {
-				let square_index = pop_lsb(&mut bb_pawns);
+			let mut bb_pinned = bb_pawns;
 
-				if self.pin_rays[square_index as usize] != 0 {
-					continue;
-				}
+			while bb_pinned > 0 {
+				let square_index = pop_lsb(&mut bb_pinned) as usize;
+				let pin_ray = context.pin_rays[square_index];
 
-				self.add_move(&board, Piece::Pawn, square_index, bb_moves, list);
+				//* A pinned pawn can still take along its pin ray */
+				if pin_ray != 0 && pin_ray & bb_moves == 0 {
+					bb_pawns &= !self.tables.square_bit[square_index];
+				}
 			}
 		}
+
+		bb_pawns
 	}
 
-	pub fn castling(&self, board: &Board, list: &mut Vec<Move>) {
-		if self.check {
-			return;
+	pub(crate) fn castling(&self, board: &Board, context: &AttackContext, list: &mut MoveList) {
+		let king_square = get_lsb_index(board.get_bitboard(Piece::King, board.get_color()));
+		let mut castle_indices = self.castle_indices(board, context);
+
+		while castle_indices > 0 {
+			let castle_index = pop_lsb(&mut castle_indices) as usize;
+
+			self.add_castle_move(king_square, castle_index, list);
+		}
+	}
+
+	// One bit per legal castle index
+	fn castle_indices(&self, board: &Board, context: &AttackContext) -> Bitboard {
+		let mut castle_indices = 0;
+
+		if context.check {
+			return castle_indices;
 		}
 
 		let color = board.get_color();
+		let inactive = !color;
 
-		let bb_empty = !board.get_occupancy();
+		let bb_occupancy = board.get_occupancy();
 		let bb_ally_king = board.get_bitboard(Piece::King, color);
+		let king_square = get_lsb_index(bb_ally_king);
 
-		let mut ally_castle_rights =
-			(CASTLE_RIGHTS[color.to_index()] & board.castle_rights) as Bitboard;
+		let bb_opp_rooks = board.get_bitboard(Piece::Rook, inactive);
+		let bb_opp_queens = board.get_bitboard(Piece::Queen, inactive);
 
-		let mut bb_moves = 0;
+		let mut ally_castle_rights =
+			(CASTLE_RIGHTS[color.to_index()] & board.get_castle_rights()) as Bitboard;
 
 		while ally_castle_rights > 0 {
 			let castle_index = pop_lsb(&mut ally_castle_rights) as usize;
 
-			let bb_castle_empty = CASTLE_EMPTY[castle_index];
-			if bb_empty & bb_castle_empty != bb_castle_empty {
+			let rook_square = board.get_castle_rook(castle_index);
+			let king_target = CASTLE_KING_INDEX[castle_index];
+			let rook_target = CASTLE_ROOK_TARGET[castle_index];
+
+			let bb_castle_pieces = self.tables.square_bit[king_square as usize]
+				| self.tables.square_bit[rook_square as usize];
+
+			let bb_castle_empty = (rank_span(king_square, king_target)
+				| rank_span(rook_square, rook_target))
+				& !bb_castle_pieces;
+			if bb_occupancy & bb_castle_empty != 0 {
 				continue;
 			}
 
-			let bb_caslte_attack = CASTLE_ATTACK[castle_index];
-			if bb_caslte_attack & !self.bb_attack != bb_caslte_attack {
+			let bb_castle_attack = rank_span(king_square, king_target);
+			if bb_castle_attack & context.bb_attack != 0 {
 				continue;
 			}
 
-			set_bit(&mut bb_moves, CASTLE_KING_INDEX[castle_index]);
-		}
+			//* In Chess960 the castling rook can be the only piece shielding the king's target */
+			let bb_castled_occupancy = bb_occupancy ^ bb_castle_pieces;
+			let rank_attack =
+				self.get_slider_attacks(Piece::Rook, king_target as usize, bb_castled_occupancy);
 
-		if bb_moves > 0 {
-			let king_square = get_lsb_index(bb_ally_king);
+			if rank_attack & (bb_opp_rooks | bb_opp_queens) != 0 {
+				continue;
+			}
 
-			self.add_move(&board, Piece::King, king_square, bb_moves, list);
+			castle_indices |= 1 << castle_index;
 		}
+
+		castle_indices
 	}
 }
@@ -1,51 +1,177 @@
-use super::MoveGenerator;
+use super::{defs::SliderBackend, MoveGenerator};
 
+#[cfg(any(not(feature = "kogge-stone-only"), feature = "magic-search"))]
+use crate::bitboard::{is_occupied, set_bit};
 use crate::{
-	bitboard::{is_occupied, set_bit, Bitboard},
+	bitboard::{pop_lsb, Bitboard},
+	board::Board,
 	color::Color,
+	notation::Square,
 	piece::Piece,
 };
 
+#[cfg(feature = "magic-search")]
 pub type AttackBoards = Vec<Bitboard>;
 
 impl MoveGenerator {
-	pub fn get_pawn_attacks(&self, square_index: usize, color: Color) -> Bitboard {
-		self.pawn[color.to_index()][square_index]
+	// Pieces of both colors attacking the square, sliders are blocked by the given occupancy
+	pub fn attackers_to(&self, board: &Board, square: Square, occupancy: Bitboard) -> Bitboard {
+		let square_index = square.to_index();
+
+		let bb_queens = board.get_bitboard(Piece::Queen, Color::White)
+			| board.get_bitboard(Piece::Queen, Color::Black);
+		let bb_rooks = board.get_bitboard(Piece::Rook, Color::White)
+			| board.get_bitboard(Piece::Rook, Color::Black)
+			| bb_queens;
+		let bb_bishops = board.get_bitboard(Piece::Bishop, Color::White)
+			| board.get_bitboard(Piece::Bishop, Color::Black)
+			| bb_queens;
+		let bb_knights = board.get_bitboard(Piece::Knight, Color::White)
+			| board.get_bitboard(Piece::Knight, Color::Black);
+		let bb_kings = board.get_bitboard(Piece::King, Color::White)
+			| board.get_bitboard(Piece::King, Color::Black);
+
+		//* A pawn attacks the square if a pawn of the other color on it would attack the pawn */
+		let bb_pawns = self.get_pawn_attacks(square_index, Color::Black)
+			& board.get_bitboard(Piece::Pawn, Color::White)
+			| self.get_pawn_attacks(square_index, Color::White)
+				& board.get_bitboard(Piece::Pawn, Color::Black);
+
+		let bb_attackers = bb_pawns
+			| self.get_non_slider_attacks(Piece::Knight, square_index) & bb_knights
+			| self.get_non_slider_attacks(Piece::King, square_index) & bb_kings
+			| self.get_slider_attacks(Piece::Rook, square_index, occupancy) & bb_rooks
+			| self.get_slider_attacks(Piece::Bishop, square_index, occupancy) & bb_bishops;
+
+		bb_attackers & occupancy
+	}
+
+	pub fn is_attacked_by(&self, board: &Board, square: Square, color: Color) -> bool {
+		let bb_attackers = self.attackers_to(board, square, board.get_occupancy());
+
+		bb_attackers & board.get_allys(color) != 0
+	}
+
+	// Every square attacked by the pieces of one color
+	pub fn attack_map(&self, board: &Board, color: Color) -> Bitboard {
+		let bb_occupancy = board.get_occupancy();
+
+		let mut bb_attack = 0;
+		let mut bb_pieces = board.get_allys(color);
+
+		while bb_pieces > 0 {
+			let square_index = pop_lsb(&mut bb_pieces);
+
+			if let Some((piece, _)) = board.get_piece_at(square_index) {
+				let square_index = square_index as usize;
+
+				bb_attack |= match piece {
+					Piece::Pawn => self.get_pawn_attacks(square_index, color),
+					Piece::King | Piece::Knight => self.get_non_slider_attacks(piece, square_index),
+					_ => self.get_slider_attacks(piece, square_index, bb_occupancy),
+				};
+			}
+		}
+
+		bb_attack
 	}
 
-	pub fn get_slider_attacks(
+	pub(crate) fn get_pawn_attacks(&self, square_index: usize, color: Color) -> Bitboard {
+		self.tables.pawn[color.to_index()][square_index]
+	}
+
+	pub(crate) fn get_slider_attacks(
 		&self,
 		piece: Piece,
 		square_index: usize,
 		occupancy: Bitboard,
 	) -> Bitboard {
 		match piece {
-			Piece::Rook => {
-				let index = self.rook_magics[square_index].get_index(occupancy);
-				self.rook[index]
-			}
-			Piece::Bishop => {
-				let index = self.bishop_magics[square_index].get_index(occupancy);
-				self.bishop[index]
-			}
+			Piece::Rook => self.get_rook_attacks(square_index, occupancy),
+			Piece::Bishop => self.get_bishop_attacks(square_index, occupancy),
 			Piece::Queen => {
-				let r_index = self.rook_magics[square_index].get_index(occupancy);
-				let b_index = self.bishop_magics[square_index].get_index(occupancy);
-				self.rook[r_index] ^ self.bishop[b_index]
+				self.get_rook_attacks(square_index, occupancy)
+					^ self.get_bishop_attacks(square_index, occupancy)
 			}
 			_ => panic!("Not a sliding piece: {}", piece.to_full_name()),
 		}
 	}
 
-	pub fn get_non_slider_attacks(&self, piece: Piece, square_index: usize) -> Bitboard {
+	fn get_rook_attacks(&self, square_index: usize, occupancy: Bitboard) -> Bitboard {
+		match self.backend {
+			#[cfg(not(feature = "kogge-stone-only"))]
+			SliderBackend::Magic => {
+				let magic = &self.tables.sliders.rook_magics[square_index];
+
+				self.tables.sliders.rook[magic.get_index(occupancy)]
+			}
+			#[cfg(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			))]
+			SliderBackend::Pext => {
+				let magic = &self.tables.sliders.rook_pext_magics[square_index];
+
+				// MoveGenerator::with_backend only accepts Pext on a BMI2 CPU
+				let index = unsafe { magic.get_pext_index(occupancy) };
+
+				self.tables.sliders.rook_pext[index]
+			}
+			#[cfg(not(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			)))]
+			SliderBackend::Pext => unreachable!("Pext is not supported in this build"),
+			#[cfg(feature = "kogge-stone-only")]
+			SliderBackend::Magic => unreachable!("Magic is not supported in this build"),
+			SliderBackend::KoggeStone => MoveGenerator::kogge_stone_rook(square_index, occupancy),
+		}
+	}
+
+	fn get_bishop_attacks(&self, square_index: usize, occupancy: Bitboard) -> Bitboard {
+		match self.backend {
+			#[cfg(not(feature = "kogge-stone-only"))]
+			SliderBackend::Magic => {
+				let magic = &self.tables.sliders.bishop_magics[square_index];
+
+				self.tables.sliders.bishop[magic.get_index(occupancy)]
+			}
+			#[cfg(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			))]
+			SliderBackend::Pext => {
+				let magic = &self.tables.sliders.bishop_pext_magics[square_index];
+
+				// MoveGenerator::with_backend only accepts Pext on a BMI2 CPU
+				let index = unsafe { magic.get_pext_index(occupancy) };
+
+				self.tables.sliders.bishop_pext[index]
+			}
+			#[cfg(not(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			)))]
+			SliderBackend::Pext => unreachable!("Pext is not supported in this build"),
+			#[cfg(feature = "kogge-stone-only")]
+			SliderBackend::Magic => unreachable!("Magic is not supported in this build"),
+			SliderBackend::KoggeStone => MoveGenerator::kogge_stone_bishop(square_index, occupancy),
+		}
+	}
+
+	pub(crate) fn get_non_slider_attacks(&self, piece: Piece, square_index: usize) -> Bitboard {
 		match piece {
-			Piece::King => self.king[square_index],
-			Piece::Knight => self.knight[square_index],
+			Piece::King => self.tables.king[square_index],
+			Piece::Knight => self.tables.knight[square_index],
 			_ => panic!("Not a king or a knight: {piece}"),
 		}
 	}
 
-	pub fn xray_attack(
+	pub(crate) fn xray_attack(
 		&self,
 		piece: Piece,
 		square_index: usize,
@@ -64,53 +190,72 @@ impl MoveGenerator {
 		}
 	}
 
-	pub fn rook_attack(square_index: u8, occupancy: Bitboard) -> Bitboard {
+	#[cfg(any(not(feature = "kogge-stone-only"), feature = "magic-search"))]
+	pub(crate) const fn rook_attack(square_index: u8, occupancy: Bitboard) -> Bitboard {
 		const OFFSETS: [i8; 4] = [1, 8, -1, -8];
 
 		let mut attack = 0;
 
 		let num_to_edge = MoveGenerator::rook_num_to_edge(square_index);
 
-		for direction in 0..4 {
+		let mut direction = 0;
+
+		while direction < 4 {
 			let offset = OFFSETS[direction];
 
-			for n in 1..=num_to_edge[direction] as i8 {
+			let mut n = 1;
+
+			while n <= num_to_edge[direction] as i8 {
 				let target_square = (square_index as i8 + offset * n) as u8;
 				set_bit(&mut attack, target_square);
 
 				if is_occupied(occupancy, target_square) {
 					break;
 				}
+
+				n += 1;
 			}
+
+			direction += 1;
 		}
 
 		attack
 	}
 
-	pub fn bishop_attack(square_index: u8, occupancy: Bitboard) -> Bitboard {
+	#[cfg(any(not(feature = "kogge-stone-only"), feature = "magic-search"))]
+	pub(crate) const fn bishop_attack(square_index: u8, occupancy: Bitboard) -> Bitboard {
 		const OFFSETS: [i8; 4] = [7, 9, -7, -9];
 
 		let mut attack = 0;
 
 		let num_to_edge = MoveGenerator::bishop_num_to_edge(square_index);
 
-		for direction in 0..4 {
+		let mut direction = 0;
+
+		while direction < 4 {
 			let offset = OFFSETS[direction];
 
-			for n in 1..=num_to_edge[direction] as i8 {
+			let mut n = 1;
+
+			while n <= num_to_edge[direction] as i8 {
 				let target_square = (square_index as i8 + offset * n) as u8;
 				set_bit(&mut attack, target_square);
 
 				if is_occupied(occupancy, target_square) {
 					break;
 				}
+
+				n += 1;
 			}
+
+			direction += 1;
 		}
 
 		attack
 	}
 
-	pub fn rook_attack_boards(square_index: u8, blockers: &[Bitboard]) -> AttackBoards {
+	#[cfg(feature = "magic-search")]
+	pub(crate) fn rook_attack_boards(square_index: u8, blockers: &[Bitboard]) -> AttackBoards {
 		let mut bb_attack_boards = Vec::new();
 
 		for occupancy in blockers {
@@ -120,7 +265,8 @@ impl MoveGenerator {
 		bb_attack_boards
 	}
 
-	pub fn bishop_attack_boards(square_index: u8, blockers: &[Bitboard]) -> AttackBoards {
+	#[cfg(feature = "magic-search")]
+	pub(crate) fn bishop_attack_boards(square_index: u8, blockers: &[Bitboard]) -> AttackBoards {
 		let mut bb_attack_boards = Vec::new();
 
 		for occupancy in blockers {
@@ -0,0 +1,84 @@
+use super::{
+	defs::{CheckKind, Move, MoveKind},
+	MoveGenerator, CASTLE_ROOK_TARGET, PAWN_PUSH_DIRECTION,
+};
+
+use crate::{bitboard::get_lsb_index, board::Board, piece::Piece};
+
+impl MoveGenerator {
+	pub fn gives_check(&self, board: &Board, to_move: Move) -> bool {
+		self.check_kind(board, to_move).is_some()
+	}
+
+	// Works on the position before the move, the move is never made
+	pub fn check_kind(&self, board: &Board, to_move: Move) -> Option<CheckKind> {
+		let color = board.get_color();
+		let inactive = !color;
+
+		let square_bit = &self.tables.square_bit;
+
+		let bb_opp_king = board.get_bitboard(Piece::King, inactive);
+		let king_square = get_lsb_index(bb_opp_king) as usize;
+
+		let start_square = to_move.get_start_square() as usize;
+		let target_square = to_move.get_target_square() as usize;
+
+		//* Occupancy after the move, and the squares our pieces leave */
+		let mut bb_occupancy = board.get_occupancy() & !square_bit[start_square];
+		let mut bb_vacated = square_bit[start_square];
+
+		let (piece, piece_square) = match to_move.kind() {
+			MoveKind::Castle => {
+				let castle_index = to_move.get_castle_index();
+				let rook_square = board.get_castle_rook(castle_index) as usize;
+				let rook_target = CASTLE_ROOK_TARGET[castle_index] as usize;
+
+				bb_occupancy &= !square_bit[rook_square];
+				bb_occupancy |= square_bit[target_square] | square_bit[rook_target];
+				bb_vacated |= square_bit[rook_square];
+
+				// Only the rook can give check after castling
+				(Piece::Rook, rook_target)
+			}
+			MoveKind::EnPassant => {
+				let pawn_square = target_square as i8 - PAWN_PUSH_DIRECTION[color.to_index()];
+
+				bb_occupancy &= !square_bit[pawn_square as usize];
+				bb_occupancy |= square_bit[target_square];
+
+				(Piece::Pawn, target_square)
+			}
+			_ => {
+				bb_occupancy |= square_bit[target_square];
+
+				let piece = to_move.promotion().unwrap_or(to_move.piece());
+
+				(piece, target_square)
+			}
+		};
+
+		let direct = bb_opp_king
+			& match piece {
+				Piece::King => 0,
+				Piece::Pawn => self.get_pawn_attacks(piece_square, color),
+				Piece::Knight => self.get_non_slider_attacks(piece, piece_square),
+				_ => self.get_slider_attacks(piece, piece_square, bb_occupancy),
+			} != 0;
+
+		//* Sliders that stayed put and now see the king through a vacated square */
+		let bb_queens = board.get_bitboard(Piece::Queen, color);
+		let bb_rooks = (board.get_bitboard(Piece::Rook, color) | bb_queens) & !bb_vacated;
+		let bb_bishops = (board.get_bitboard(Piece::Bishop, color) | bb_queens) & !bb_vacated;
+
+		let bb_discovered = (self.get_slider_attacks(Piece::Rook, king_square, bb_occupancy)
+			& bb_rooks)
+			| (self.get_slider_attacks(Piece::Bishop, king_square, bb_occupancy) & bb_bishops);
+
+		match (direct, bb_discovered != 0) {
+			(true, true) => Some(CheckKind::Double),
+			(true, false) => Some(CheckKind::Direct),
+			(false, true) => Some(CheckKind::Discovered),
+			(false, false) => None,
+		}
+	}
+}
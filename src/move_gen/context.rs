@@ -0,0 +1,61 @@
+use crate::{bitboard::Bitboard, notation::Square};
+
+// Pins, checks and attacked squares of one position, from the side to move's point of view
+pub struct AttackContext {
+	pub(crate) check: bool,
+	pub(crate) double_check: bool,
+
+	pub(crate) pin_rays: [Bitboard; 64],
+	pub(crate) check_ray: Bitboard,
+	pub(crate) bb_attack: Bitboard,
+}
+
+impl Default for AttackContext {
+	fn default() -> Self {
+		Self {
+			check: false,
+			double_check: false,
+
+			pin_rays: [0; 64],
+			check_ray: 0,
+			bb_attack: 0,
+		}
+	}
+}
+
+impl AttackContext {
+	pub fn is_check(&self) -> bool {
+		self.check
+	}
+
+	pub fn is_double_check(&self) -> bool {
+		self.double_check
+	}
+
+	// Squares the opponent attacks, seen through the king of the side to move
+	pub fn attacked_squares(&self) -> Bitboard {
+		self.bb_attack
+	}
+
+	// Squares that block or capture the checking pieces
+	pub fn check_ray(&self) -> Bitboard {
+		self.check_ray
+	}
+
+	// Squares a pinned piece may still move to, 0 when it is not pinned
+	pub fn pin_ray(&self, square: Square) -> Bitboard {
+		self.pin_rays[square.to_index()]
+	}
+
+	pub(crate) fn isolate_attack_pin_checks(&self, attack: &mut Bitboard, square_index: usize) {
+		if self.check {
+			*attack &= self.check_ray;
+		}
+
+		let pin_ray = self.pin_rays[square_index];
+
+		if pin_ray != 0 {
+			*attack &= pin_ray;
+		}
+	}
+}
@@ -1,56 +1,133 @@
 use std::fmt::Display;
 
-use super::MoveGenerator;
+use super::{move_list::MoveList, MoveGenerator, CASTLE_KING_INDEX, PAWN_PROMOTION_RANK};
 
 use crate::{
 	bitboard::{pop_lsb, Bitboard},
 	board::Board,
-	color::Color,
-	notation::Notation,
+	notation::{Notation, Square},
 	piece::{Piece, PROMOTION_PIECES},
 };
 
-const PAWN_PROMOTION_RANK: [Bitboard; 2] = [0xff00000000000000, 0x00000000000000ff];
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MoveKind {
+	Quiet,
+	DoublePush,
+	Capture,
+	EnPassant,
+	Castle,
+	Promotion,
+}
+
+impl MoveKind {
+	pub fn new(kind_index: usize) -> Self {
+		match kind_index {
+			0 => MoveKind::Quiet,
+			1 => MoveKind::DoublePush,
+			2 => MoveKind::Capture,
+			3 => MoveKind::EnPassant,
+			4 => MoveKind::Castle,
+			5 => MoveKind::Promotion,
+			_ => panic!(),
+		}
+	}
+
+	pub fn to_index(&self) -> usize {
+		*self as usize
+	}
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckKind {
+	Direct,
+	Discovered,
+	Double,
+}
+
+// How sliding piece attacks are looked up
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliderBackend {
+	// Always declared, but not supported with the kogge-stone-only feature
+	Magic,
+	// Always declared, but only supported with the pext feature on a BMI2 CPU
+	Pext,
+	// Occluded fills, slower but without any table
+	KoggeStone,
+}
+
+impl SliderBackend {
+	// The fastest backend this CPU can run
+	pub fn detect() -> Self {
+		if SliderBackend::Pext.is_supported() {
+			return SliderBackend::Pext;
+		}
+
+		if SliderBackend::Magic.is_supported() {
+			return SliderBackend::Magic;
+		}
+
+		SliderBackend::KoggeStone
+	}
 
-pub struct Shift;
+	// Every backend built in and supported by this CPU
+	pub fn available() -> Vec<Self> {
+		let backends = [
+			SliderBackend::Magic,
+			SliderBackend::Pext,
+			SliderBackend::KoggeStone,
+		];
+
+		backends
+			.into_iter()
+			.filter(|backend| backend.is_supported())
+			.collect()
+	}
+
+	pub fn is_supported(&self) -> bool {
+		match self {
+			SliderBackend::Magic => !cfg!(feature = "kogge-stone-only"),
+			SliderBackend::KoggeStone => true,
+			#[cfg(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			))]
+			SliderBackend::Pext => is_x86_feature_detected!("bmi2"),
+			#[cfg(not(all(
+				feature = "pext",
+				target_arch = "x86_64",
+				not(feature = "kogge-stone-only")
+			)))]
+			SliderBackend::Pext => false,
+		}
+	}
+}
+
+struct Shift;
 impl Shift {
 	const PIECE_MOVED: usize = 0;
 	const START_SQUARE: usize = 3;
 	const TARGET_SQUARE: usize = 9;
-	const MOVE_TYPE: usize = 15;
+	const MOVE_KIND: usize = 15;
 	const PROMOTION: usize = 18;
-	const CASTLE_RIGHT: usize = 21;
+	const CASTLE_INDEX: usize = 21;
 	const PREV_ENPASSANT: usize = 25;
 	const PREV_CASTLE_RIGHTS: usize = 31;
 	const PIECE_CAPTURED: usize = 35;
+	const PREV_HALFMOVE_CLOCK: usize = 38;
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Move {
 	data: u64,
 }
 
 impl Display for Move {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let square_index = self.get_start_square() as usize;
-		let target_square = self.get_target_square() as usize;
-		let promotion_piece = Piece::new(self.get_promotion());
-
-		if promotion_piece != Piece::King {
-			write!(
-				f,
-				"{}{}{}",
-				Notation::from(square_index),
-				Notation::from(target_square),
-				promotion_piece
-			)
+		if let Some(promotion_piece) = self.promotion() {
+			write!(f, "{}{}{}", self.from(), self.to(), promotion_piece)
 		} else {
-			write!(
-				f,
-				"{}{}",
-				Notation::from(square_index),
-				Notation::from(target_square)
-			)
+			write!(f, "{}{}", self.from(), self.to())
 		}
 	}
 }
@@ -59,56 +136,124 @@ impl Move {
 	const PIECE_MOVED_MASK: u64 = 0x0000000007;
 	const START_SQUARE_MASK: u64 = 0x00000001f8;
 	const TARGET_SQUARE_MASK: u64 = 0x0000007e00;
-	const MOVE_TYPE_MASK: u64 = 0x0000038000;
+	const MOVE_KIND_MASK: u64 = 0x0000038000;
 	const PROMOTION_MASK: u64 = 0x00001c0000;
-	const CASTLE_RIGHT_MASK: u64 = 0x0001e00000;
+	const CASTLE_INDEX_MASK: u64 = 0x0001e00000;
 	const PREV_ENPASSANT_MASK: u64 = 0x007e000000;
 	const PREV_CASTLE_RIGHTS_MASK: u64 = 0x0780000000;
 	const PIECE_CAPTURED_MASK: u64 = 0x3800000000;
+	const PREV_HALFMOVE_CLOCK_MASK: u64 = 0x3fffc000000000;
+
+	//* Piece, squares, kind, promotion and castle index, the undo data is left out */
+	const MOVE_BITS_MASK: u64 = 0x0001ffffff;
+	//* Moves the captured piece from bit 35 to bit 25, right above the move bits */
+	const CAPTURED_BITS_OFFSET: usize = 10;
 
-	pub fn new(data: u64) -> Self {
+	pub(crate) fn new(data: u64) -> Self {
 		Move { data }
 	}
 
-	pub fn set_prev_enpassant(&mut self, enpassant: Option<usize>) {
+	pub(crate) fn set_prev_enpassant(&mut self, enpassant: Option<usize>) {
 		if let Some(ep) = enpassant {
 			self.data |= (ep as u64) << Shift::PREV_ENPASSANT;
 		}
 	}
 
-	pub fn set_prev_castle_rights(&mut self, castle_rights: usize) {
+	pub(crate) fn set_prev_castle_rights(&mut self, castle_rights: usize) {
 		self.data |= (castle_rights as u64) << Shift::PREV_CASTLE_RIGHTS;
 	}
 
-	pub fn set_piece_captured(&mut self, piece: usize) {
+	pub(crate) fn set_prev_halfmove_clock(&mut self, halfmove_clock: usize) {
+		self.data |= ((halfmove_clock as u64) << Shift::PREV_HALFMOVE_CLOCK)
+			& Move::PREV_HALFMOVE_CLOCK_MASK;
+	}
+
+	pub(crate) fn set_piece_captured(&mut self, piece: usize) {
+		self.data &= !Move::PIECE_CAPTURED_MASK;
 		self.data |= (piece as u64) << Shift::PIECE_CAPTURED;
 	}
 
-	pub fn get_moved_piece(&self) -> usize {
-		(self.data & Move::PIECE_MOVED_MASK) as usize
+	pub fn piece(&self) -> Piece {
+		Piece::new((self.data & Move::PIECE_MOVED_MASK) as usize)
 	}
 
-	pub fn get_start_square(&self) -> u8 {
-		((self.data & Move::START_SQUARE_MASK) >> Shift::START_SQUARE) as u8
+	pub fn from(&self) -> Square {
+		Notation::from(self.get_start_square() as usize)
 	}
 
-	pub fn get_target_square(&self) -> u8 {
-		((self.data & Move::TARGET_SQUARE_MASK) >> Shift::TARGET_SQUARE) as u8
+	pub fn to(&self) -> Square {
+		Notation::from(self.get_target_square() as usize)
+	}
+
+	pub fn kind(&self) -> MoveKind {
+		MoveKind::new(((self.data & Move::MOVE_KIND_MASK) >> Shift::MOVE_KIND) as usize)
 	}
 
-	pub fn get_move_type(&self) -> usize {
-		((self.data & Move::MOVE_TYPE_MASK) >> Shift::MOVE_TYPE) as usize
+	pub fn promotion(&self) -> Option<Piece> {
+		let piece = ((self.data & Move::PROMOTION_MASK) >> Shift::PROMOTION) as usize;
+
+		match piece {
+			0 => None,
+			_ => Some(Piece::new(piece)),
+		}
 	}
 
-	pub fn get_promotion(&self) -> usize {
-		((self.data & Move::PROMOTION_MASK) >> Shift::PROMOTION) as usize
+	// En passant takes a pawn that is not on the target square
+	pub fn captured(&self) -> Option<Piece> {
+		if self.kind() == MoveKind::EnPassant {
+			return Some(Piece::Pawn);
+		}
+
+		self.get_piece_captured().map(Piece::new)
 	}
 
-	pub fn get_castle_right(&self) -> usize {
-		((self.data & Move::CASTLE_RIGHT_MASK) >> Shift::CASTLE_RIGHT) as usize
+	pub fn is_capture(&self) -> bool {
+		self.captured().is_some()
 	}
 
-	pub fn get_prev_enpassant(&self) -> Option<usize> {
+	// Small enough for a hash table entry, the captured piece moves down next to the move bits
+	pub fn to_bits(&self) -> u32 {
+		let captured = (self.data & Move::PIECE_CAPTURED_MASK) >> Move::CAPTURED_BITS_OFFSET;
+
+		((self.data & Move::MOVE_BITS_MASK) | captured) as u32
+	}
+
+	// None when a field holds no piece, kind or castle, a valid move still needs is_legal
+	pub fn from_bits(bits: u32) -> Option<Self> {
+		let bits = bits as u64;
+		let captured_bits = Move::PIECE_CAPTURED_MASK >> Move::CAPTURED_BITS_OFFSET;
+
+		if bits & !(Move::MOVE_BITS_MASK | captured_bits) != 0 {
+			return None;
+		}
+
+		let captured = (bits & captured_bits) << Move::CAPTURED_BITS_OFFSET;
+		let to_move = Move::new(bits & Move::MOVE_BITS_MASK | captured);
+
+		let field = |mask: u64, shift: usize| ((to_move.data & mask) >> shift) as usize;
+
+		let is_valid = field(Move::PIECE_MOVED_MASK, Shift::PIECE_MOVED) <= 5
+			&& field(Move::MOVE_KIND_MASK, Shift::MOVE_KIND) <= 5
+			&& matches!(field(Move::PROMOTION_MASK, Shift::PROMOTION), 0 | 2..=5)
+			&& field(Move::CASTLE_INDEX_MASK, Shift::CASTLE_INDEX) <= 3
+			&& field(Move::PIECE_CAPTURED_MASK, Shift::PIECE_CAPTURED) <= 5;
+
+		is_valid.then_some(to_move)
+	}
+
+	pub(crate) fn get_start_square(&self) -> u8 {
+		((self.data & Move::START_SQUARE_MASK) >> Shift::START_SQUARE) as u8
+	}
+
+	pub(crate) fn get_target_square(&self) -> u8 {
+		((self.data & Move::TARGET_SQUARE_MASK) >> Shift::TARGET_SQUARE) as u8
+	}
+
+	pub(crate) fn get_castle_index(&self) -> usize {
+		((self.data & Move::CASTLE_INDEX_MASK) >> Shift::CASTLE_INDEX) as usize
+	}
+
+	pub(crate) fn get_prev_enpassant(&self) -> Option<usize> {
 		let ep = ((self.data & Move::PREV_ENPASSANT_MASK) >> Shift::PREV_ENPASSANT) as usize;
 
 		if ep != 0 {
@@ -118,11 +263,15 @@ impl Move {
 		return None;
 	}
 
-	pub fn get_prev_castle_rights(&self) -> usize {
+	pub(crate) fn get_prev_castle_rights(&self) -> usize {
 		((self.data & Move::PREV_CASTLE_RIGHTS_MASK) >> Shift::PREV_CASTLE_RIGHTS) as usize
 	}
 
-	pub fn get_piece_captured(&self) -> Option<usize> {
+	pub(crate) fn get_prev_halfmove_clock(&self) -> usize {
+		((self.data & Move::PREV_HALFMOVE_CLOCK_MASK) >> Shift::PREV_HALFMOVE_CLOCK) as usize
+	}
+
+	pub(crate) fn get_piece_captured(&self) -> Option<usize> {
 		let piece = ((self.data & Move::PIECE_CAPTURED_MASK) >> Shift::PIECE_CAPTURED) as usize;
 
 		if piece != 0 {
@@ -134,70 +283,53 @@ impl Move {
 }
 
 impl MoveGenerator {
-	pub fn add_move(
+	pub(crate) fn add_move(
 		&self,
 		board: &Board,
 		piece: Piece,
 		square_index: u8,
 		bb_moves: Bitboard,
-		list: &mut Vec<Move>,
+		list: &mut MoveList,
 	) {
 		let mut bb_moves = bb_moves;
 
 		let color = board.get_color();
 		let promotion_rank = PAWN_PROMOTION_RANK[color.to_index()];
 		let is_pawn = piece == Piece::Pawn;
-		let is_king = piece == Piece::King;
 
 		while bb_moves > 0 {
 			let target_square = pop_lsb(&mut bb_moves) as usize;
 
-			let mut move_type = 0;
-			let enpassant = match board.enpassant {
-				Some(ep) => {
-					if is_pawn && (ep == target_square) {
-						1
-					} else {
-						0
-					}
-				}
-				None => 0,
+			let piece_captured = board
+				.get_piece_at(target_square as u8)
+				.map(|(piece, _)| piece);
+
+			let enpassant = is_pawn && board.get_enpassant() == Some(target_square);
+			let promotion =
+				is_pawn && ((self.tables.square_bit[target_square] & promotion_rank) != 0);
+			let double_push = is_pawn && square_index.abs_diff(target_square as u8) == 16;
+
+			let move_kind = if enpassant {
+				MoveKind::EnPassant
+			} else if promotion {
+				MoveKind::Promotion
+			} else if double_push {
+				MoveKind::DoublePush
+			} else if piece_captured.is_some() {
+				MoveKind::Capture
+			} else {
+				MoveKind::Quiet
 			};
 
-			let promotion = is_pawn && ((self.square_bit[target_square] & promotion_rank) != 0);
-
-			let direction = square_index as i8 - target_square as i8;
-			let castle = is_king && direction.abs() == 2;
-
-			move_type |= enpassant;
-			move_type |= (promotion as u64) << 1;
-			move_type |= (castle as u64) << 2;
-
 			let mut data = 0;
 
 			data |= (piece.to_index() as u64) << Shift::PIECE_MOVED;
 			data |= (square_index as u64) << Shift::START_SQUARE;
 			data |= (target_square as u64) << Shift::TARGET_SQUARE;
-			data |= move_type << Shift::MOVE_TYPE;
-
-			if castle {
-				let castle_right;
-
-				if color == Color::White {
-					if direction > 0 {
-						castle_right = 0b0001;
-					} else {
-						castle_right = 0b0010;
-					}
-				} else {
-					if direction > 0 {
-						castle_right = 0b0100;
-					} else {
-						castle_right = 0b1000;
-					}
-				}
+			data |= (move_kind.to_index() as u64) << Shift::MOVE_KIND;
 
-				data |= castle_right << Shift::CASTLE_RIGHT;
+			if let Some(piece) = piece_captured {
+				data |= (piece.to_index() as u64) << Shift::PIECE_CAPTURED;
 			}
 
 			if !promotion {
@@ -211,4 +343,21 @@ impl MoveGenerator {
 			}
 		}
 	}
+
+	pub(crate) fn add_castle_move(
+		&self,
+		king_square: u8,
+		castle_index: usize,
+		list: &mut MoveList,
+	) {
+		let mut data = 0;
+
+		data |= (Piece::King.to_index() as u64) << Shift::PIECE_MOVED;
+		data |= (king_square as u64) << Shift::START_SQUARE;
+		data |= (CASTLE_KING_INDEX[castle_index] as u64) << Shift::TARGET_SQUARE;
+		data |= (MoveKind::Castle.to_index() as u64) << Shift::MOVE_KIND;
+		data |= (castle_index as u64) << Shift::CASTLE_INDEX;
+
+		list.push(Move::new(data));
+	}
 }
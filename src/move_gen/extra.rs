@@ -1,17 +1,17 @@
-use super::MoveGenerator;
+use super::{context::AttackContext, MoveGenerator};
 
 use crate::{
-	bitboard::{get_lsb_index, pop_lsb, pop_lsb_to_bitboard, Bitboard},
+	bitboard::{get_lsb_index, pop_lsb, pop_lsb_to_bitboard},
 	board::Board,
 	piece::Piece,
 };
 
 impl MoveGenerator {
-	pub fn calculate_attack_mask(&mut self, board: &Board) {
-		self.reset();
+	pub fn calculate_attack_mask(&self, board: &Board) -> AttackContext {
+		let mut context = AttackContext::default();
 
-		self.find_pins_checks(&board, Piece::Rook);
-		self.find_pins_checks(&board, Piece::Bishop);
+		self.find_pins_checks(&board, Piece::Rook, &mut context);
+		self.find_pins_checks(&board, Piece::Bishop, &mut context);
 
 		let color = board.get_color();
 		let inactive = !color;
@@ -21,7 +21,8 @@ impl MoveGenerator {
 		//* King attacks */
 		let piece = Piece::King;
 		let bb_opp_king = board.get_bitboard(piece, inactive);
-		self.bb_attack |= self.get_non_slider_attacks(piece, get_lsb_index(bb_opp_king) as usize);
+		context.bb_attack |=
+			self.get_non_slider_attacks(piece, get_lsb_index(bb_opp_king) as usize);
 
 		//* Pawn attacks */
 		let piece = Piece::Pawn;
@@ -34,16 +35,16 @@ impl MoveGenerator {
 			let square_index = get_lsb_index(square_bit) as usize;
 			let capture_mask = self.get_pawn_attacks(square_index, inactive);
 
-			self.bb_attack |= capture_mask;
+			context.bb_attack |= capture_mask;
 
 			let in_check = capture_mask & bb_ally_king == bb_ally_king;
 
 			if !is_pawn_check && in_check {
 				is_pawn_check = true;
 
-				self.double_check = self.check;
-				self.check = true;
-				self.check_ray |= square_bit;
+				context.double_check = context.check;
+				context.check = true;
+				context.check_ray |= square_bit;
 			}
 		}
 
@@ -58,16 +59,16 @@ impl MoveGenerator {
 			let square_index = get_lsb_index(square_bit) as usize;
 			let jump_mask = self.get_non_slider_attacks(piece, square_index);
 
-			self.bb_attack |= jump_mask;
+			context.bb_attack |= jump_mask;
 
 			let in_check = jump_mask & bb_ally_king == bb_ally_king;
 
 			if !is_knight_check && in_check {
 				is_knight_check = true;
 
-				self.double_check = self.check;
-				self.check = true;
-				self.check_ray |= square_bit;
+				context.double_check = context.check;
+				context.check = true;
+				context.check_ray |= square_bit;
 			}
 		}
 
@@ -84,7 +85,7 @@ impl MoveGenerator {
 			let square_index = pop_lsb(&mut bb_attackers) as usize;
 			let attack = self.get_slider_attacks(piece, square_index, bb_no_king_occ);
 
-			self.bb_attack |= attack;
+			context.bb_attack |= attack;
 		}
 
 		//* Bishop and Queen attacks */
@@ -97,23 +98,13 @@ impl MoveGenerator {
 			let square_index = pop_lsb(&mut bb_attackers) as usize;
 			let attack = self.get_slider_attacks(piece, square_index, bb_no_king_occ);
 
-			self.bb_attack |= attack;
+			context.bb_attack |= attack;
 		}
-	}
-
-	fn reset(&mut self) {
-		self.check = false;
-		self.double_check = false;
 
-		for pin_ray in &mut self.pin_rays {
-			*pin_ray = 0;
-		}
-
-		self.check_ray = 0;
-		self.bb_attack = 0;
+		context
 	}
 
-	fn find_pins_checks(&mut self, board: &Board, piece: Piece) {
+	fn find_pins_checks(&self, board: &Board, piece: Piece, context: &mut AttackContext) {
 		let color = board.get_color();
 		let inactive = !color;
 
@@ -133,16 +124,16 @@ impl MoveGenerator {
 		if bb_attacking_pieces > 0 {
 			let mut bb_attackers = attack & bb_attacking_pieces;
 
-			while bb_attackers > 0 && !self.double_check {
-				self.double_check = self.check;
-				self.check = true;
+			while bb_attackers > 0 && !context.double_check {
+				context.double_check = context.check;
+				context.check = true;
 
 				let square_index = pop_lsb(&mut bb_attackers) as usize;
-				let bb_square = self.square_bit[square_index];
+				let bb_square = self.tables.square_bit[square_index];
 
 				let _attack = self.get_slider_attacks(piece, square_index, bb_occupancy);
 
-				self.check_ray |= attack & _attack | bb_square;
+				context.check_ray |= attack & _attack | bb_square;
 			}
 		}
 
@@ -157,7 +148,7 @@ impl MoveGenerator {
 
 			while bb_attackers > 0 {
 				let square_index = pop_lsb(&mut bb_attackers) as usize;
-				let bb_square = self.square_bit[square_index];
+				let bb_square = self.tables.square_bit[square_index];
 
 				let (pinner_xray, pinner_attack) =
 					self.xray_attack(piece, square_index, bb_ally_pieces, bb_occupancy);
@@ -165,20 +156,8 @@ impl MoveGenerator {
 				let pin_ray = (king_xray | pinner_xray) & (attack | pinner_attack) | bb_square;
 				let pinned_square = get_lsb_index((attack & pinner_attack) & bb_blockers) as usize;
 
-				self.pin_rays[pinned_square] |= pin_ray;
+				context.pin_rays[pinned_square] |= pin_ray;
 			}
 		}
 	}
-
-	pub fn isolate_attack_pin_checks(&self, attack: &mut Bitboard, square_index: usize) {
-		if self.check {
-			*attack &= self.check_ray;
-		}
-
-		let pin_ray = self.pin_rays[square_index];
-
-		if pin_ray != 0 {
-			*attack &= pin_ray;
-		}
-	}
 }
@@ -1,14 +1,16 @@
-use super::MoveGenerator;
+#[cfg(not(feature = "kogge-stone-only"))]
+use super::tables::SliderTables;
+use super::{tables::Tables, MoveGenerator};
 
+#[cfg(not(feature = "kogge-stone-only"))]
+use crate::magic::{Magic, BISHOP_MAGICS, BISHOP_TABLE_SIZE, ROOK_MAGICS, ROOK_TABLE_SIZE};
 use crate::{
 	bitboard::{set_bit, Bitboard},
 	board::Board,
 	color::Color,
-	magic::{Magic, BISHOP_MAGIC_NR, ROOK_MAGIC_NR},
-	piece::Piece,
 };
 
-fn set_pawn_capture(
+const fn set_pawn_capture(
 	bitboard: &mut Bitboard,
 	square_index: i8,
 	right_capture: i8,
@@ -17,41 +19,54 @@ fn set_pawn_capture(
 ) {
 	//* Right capture */
 	if file != 0 {
-		set_bit(&mut *bitboard, (square_index + right_capture) as u8);
+		set_bit(bitboard, (square_index + right_capture) as u8);
 	}
 
 	//* Left capture */
 	if file != 7 {
-		set_bit(&mut *bitboard, (square_index + left_capture) as u8);
+		set_bit(bitboard, (square_index + left_capture) as u8);
 	}
 }
 
-impl MoveGenerator {
-	pub fn init(&mut self) {
-		self.init_king();
-		self.init_pawn();
-		self.init_knight();
-		self.init_magic(Piece::Rook);
-		self.init_magic(Piece::Bishop);
+//* Everything is evaluated while compiling, so a bad magic number fails the build */
+impl Tables {
+	pub(crate) const fn new() -> Self {
+		let mut square_bit = [0; 64];
+		let mut square_index = 0;
 
-		for square_index in 0..64usize {
-			self.square_bit[square_index] = 1 << square_index
+		while square_index < 64 {
+			square_bit[square_index] = 1 << square_index;
+			square_index += 1;
+		}
+
+		Self {
+			king: Tables::init_king(),
+			pawn: Tables::init_pawn(),
+			knight: Tables::init_knight(),
+			#[cfg(not(feature = "kogge-stone-only"))]
+			sliders: SliderTables::new(),
+
+			square_bit,
 		}
 	}
 
-	fn init_king(&mut self) {
-		let king = &mut self.king[..];
+	const fn init_king() -> [Bitboard; 64] {
+		let mut king = [0; 64];
 
 		let level_offets = [1, 8, -1, -8];
 		let diagonal_offsets = [7, 9, -7, -9];
 
-		for square_index in 0..64 {
+		let mut square_index = 0;
+
+		while square_index < 64 {
 			let next = square_index as usize;
 
 			let level_num_to_edge = MoveGenerator::rook_num_to_edge(square_index);
 			let diagonal_num_to_edge = MoveGenerator::bishop_num_to_edge(square_index);
 
-			for direction_index in 0..4 {
+			let mut direction_index = 0;
+
+			while direction_index < 4 {
 				//* Level */
 				if level_num_to_edge[direction_index] > 0 {
 					let target_square = square_index as i8 + level_offets[direction_index];
@@ -63,18 +78,28 @@ impl MoveGenerator {
 					let target_square = square_index as i8 + diagonal_offsets[direction_index];
 					set_bit(&mut king[next], target_square as u8);
 				}
+
+				direction_index += 1;
 			}
+
+			square_index += 1;
 		}
+
+		king
 	}
 
-	fn init_pawn(&mut self) {
-		let pawn = &mut self.pawn[..];
+	const fn init_pawn() -> [[Bitboard; 64]; 2] {
+		let mut pawn = [[0; 64]; 2];
+
+		let white_index = Color::White as usize;
+		let black_index = Color::Black as usize;
+
+		let mut rank = 0;
 
-		let white_index = Color::White.to_index();
-		let black_index = Color::Black.to_index();
+		while rank < 8 {
+			let mut file = 0;
 
-		for rank in 0..8 {
-			for file in 0..8 {
+			while file < 8 {
 				let square_index = Board::to_square_index(rank, file) as usize;
 
 				//* White captures */
@@ -98,101 +123,178 @@ impl MoveGenerator {
 						file,
 					);
 				}
+
+				file += 1;
 			}
+
+			rank += 1;
 		}
+
+		pawn
 	}
 
-	fn init_knight(&mut self) {
-		let knight = &mut self.knight[..];
+	const fn init_knight() -> [Bitboard; 64] {
+		let mut knight = [0; 64];
 
 		let offsets = [17, 15, 10, 6, -6, -10, -15, -17];
-		let range = 0..64;
 
-		for rank in 0..8i8 {
-			for file in 0..8i8 {
+		let mut rank = 0;
+
+		while rank < 8i8 {
+			let mut file = 0;
+
+			while file < 8i8 {
 				let square_index = Board::to_square_index(rank as u8, file as u8) as i8;
 
-				for offset in offsets {
-					let target_square = square_index + offset;
+				let mut offset_index = 0;
+
+				while offset_index < offsets.len() {
+					let target_square = square_index + offsets[offset_index];
 
-					if range.contains(&target_square) {
+					if target_square >= 0 && target_square < 64 {
 						let y = target_square / 8;
 						let x = target_square - y * 8;
 
-						let max_distance = i8::max((file - x).abs(), (rank - y).abs());
+						let file_distance = (file - x).abs();
+						let rank_distance = (rank - y).abs();
+
+						let max_distance = if file_distance > rank_distance {
+							file_distance
+						} else {
+							rank_distance
+						};
 
 						if max_distance == 2 {
 							set_bit(&mut knight[square_index as usize], target_square as u8);
 						}
 					}
+
+					offset_index += 1;
 				}
+
+				file += 1;
 			}
+
+			rank += 1;
 		}
+
+		knight
 	}
+}
 
-	fn init_magic(&mut self, piece: Piece) {
-		let ok = piece == Piece::Rook || piece == Piece::Bishop;
-		assert!(ok, "Illigal piece: {}", piece.to_full_name());
+#[cfg(not(feature = "kogge-stone-only"))]
+impl SliderTables {
+	const fn new() -> Self {
+		let rook = SliderTables::init_magic::<ROOK_TABLE_SIZE>(&ROOK_MAGICS, true);
+		let bishop = SliderTables::init_magic::<BISHOP_TABLE_SIZE>(&BISHOP_MAGICS, false);
+
+		#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+		let (rook_pext_magics, rook_pext) = SliderTables::init_pext(&ROOK_MAGICS, &rook);
+		#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+		let (bishop_pext_magics, bishop_pext) = SliderTables::init_pext(&BISHOP_MAGICS, &bishop);
+
+		Self {
+			rook,
+			bishop,
+			rook_magics: ROOK_MAGICS,
+			bishop_magics: BISHOP_MAGICS,
+			#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+			rook_pext,
+			#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+			bishop_pext,
+			#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+			rook_pext_magics,
+			#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+			bishop_pext_magics,
+		}
+	}
 
-		let is_rook = piece == Piece::Rook;
-		let mut offset = 0;
+	//* Squares can share slots, as long as they hold the same attacks */
+	const fn init_magic<const SIZE: usize>(
+		magics: &[Magic; 64],
+		is_rook: bool,
+	) -> [Bitboard; SIZE] {
+		let mut attacks_table = [0; SIZE];
+		let mut square_index = 0;
 
-		let magics_table = if is_rook {
-			&mut self.rook_magics[..]
-		} else {
-			&mut self.bishop_magics[..]
-		};
+		while square_index < 64 {
+			let magic = magics[square_index];
 
-		for square_index in 0..64 {
 			let mask = if is_rook {
-				MoveGenerator::rook_mask(square_index)
+				MoveGenerator::rook_mask(square_index as u8)
 			} else {
-				MoveGenerator::bishop_mask(square_index)
+				MoveGenerator::bishop_mask(square_index as u8)
 			};
+			assert!(magic.mask == mask, "Mask mismatch. Error in Magics.");
 
-			let bits = mask.count_ones();
-			let permutations = 2u64.pow(bits);
-			let end = offset + permutations - 1;
+			// Carry-Rippler
+			// https://www.chessprogramming.org/Traversing_Subsets_of_a_Set
+			let mut blocker_board = 0u64;
 
-			let blocker_boards = MoveGenerator::blocker_boards(mask);
-			let attack_boards = if is_rook {
-				MoveGenerator::rook_attack_boards(square_index, &blocker_boards)
-			} else {
-				MoveGenerator::bishop_attack_boards(square_index, &blocker_boards)
-			};
+			loop {
+				let index = magic.get_index(blocker_board);
+				let attack_board = if is_rook {
+					MoveGenerator::rook_attack(square_index as u8, blocker_board)
+				} else {
+					MoveGenerator::bishop_attack(square_index as u8, blocker_board)
+				};
 
-			let mut magic = Magic::default();
+				assert!(index < SIZE, "Indexing error. Error in Magics.");
 
-			magic.mask = mask;
-			magic.shift = (64 - bits) as u8;
-			magic.offset = offset;
-			magic.nr = if is_rook {
-				ROOK_MAGIC_NR[square_index as usize]
-			} else {
-				BISHOP_MAGIC_NR[square_index as usize]
-			};
+				if attacks_table[index] != 0 && attacks_table[index] != attack_board {
+					panic!("Attack table index holds other attacks. Error in Magics.");
+				}
 
-			for next in 0..permutations as usize {
-				let index = magic.get_index(blocker_boards[next]);
-				let attacks_table = if is_rook {
-					&mut self.rook[..]
-				} else {
-					&mut self.bishop[..]
-				};
+				attacks_table[index] = attack_board;
 
-				if attacks_table[index] == 0 {
-					let fail_low = index < offset as usize;
-					let fail_high = index > end as usize;
-					assert!(!fail_low && !fail_high, "Indexing error. Error in Magics.");
+				blocker_board = blocker_board.wrapping_sub(mask) & mask;
 
-					attacks_table[index] = attack_boards[next];
-				} else {
-					panic!("Attack table index not empty. Error in Magics.");
+				if blocker_board == 0 {
+					break;
+				}
+			}
+
+			square_index += 1;
+		}
+
+		attacks_table
+	}
+
+	// Carry-Rippler walks the blockers in the same order pext numbers them, every square after the
+	// other since pext indices are dense
+	#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+	const fn init_pext<const SIZE: usize, const PEXT_SIZE: usize>(
+		magics: &[Magic; 64],
+		magic_table: &[Bitboard; SIZE],
+	) -> ([Magic; 64], [Bitboard; PEXT_SIZE]) {
+		let mut pext_magics = *magics;
+		let mut attacks_table = [0; PEXT_SIZE];
+
+		let mut index = 0;
+		let mut square_index = 0;
+
+		while square_index < 64 {
+			let magic = magics[square_index];
+			pext_magics[square_index].offset = index as u64;
+
+			let mut blocker_board = 0u64;
+
+			loop {
+				attacks_table[index] = magic_table[magic.get_index(blocker_board)];
+
+				index += 1;
+				blocker_board = blocker_board.wrapping_sub(magic.mask) & magic.mask;
+
+				if blocker_board == 0 {
+					break;
 				}
 			}
 
-			magics_table[square_index as usize] = magic;
-			offset += permutations;
+			square_index += 1;
 		}
+
+		assert!(index == PEXT_SIZE, "Table size mismatch. Error in Magics.");
+
+		(pext_magics, attacks_table)
 	}
 }
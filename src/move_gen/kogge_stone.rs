@@ -0,0 +1,62 @@
+use super::MoveGenerator;
+
+use crate::bitboard::Bitboard;
+
+const NOT_A_FILE: Bitboard = 0xfefefefefefefefe;
+const NOT_H_FILE: Bitboard = 0x7f7f7f7f7f7f7f7f;
+
+//* Shift and wrap guard of every direction */
+const NORTH: (i8, Bitboard) = (8, Bitboard::MAX);
+const SOUTH: (i8, Bitboard) = (-8, Bitboard::MAX);
+const EAST: (i8, Bitboard) = (1, NOT_A_FILE);
+const WEST: (i8, Bitboard) = (-1, NOT_H_FILE);
+const NORTH_EAST: (i8, Bitboard) = (9, NOT_A_FILE);
+const NORTH_WEST: (i8, Bitboard) = (7, NOT_H_FILE);
+const SOUTH_EAST: (i8, Bitboard) = (-7, NOT_A_FILE);
+const SOUTH_WEST: (i8, Bitboard) = (-9, NOT_H_FILE);
+
+fn shift(bitboard: Bitboard, offset: i8) -> Bitboard {
+	if offset > 0 {
+		bitboard << offset
+	} else {
+		bitboard >> -offset
+	}
+}
+
+// Occluded fill
+// https://www.chessprogramming.org/Kogge-Stone_Algorithm
+fn ray_attack(bb_slider: Bitboard, bb_empty: Bitboard, (offset, wrap): (i8, Bitboard)) -> Bitboard {
+	let mut generator = bb_slider;
+	let mut propagator = bb_empty & wrap;
+
+	generator |= propagator & shift(generator, offset);
+	propagator &= shift(propagator, offset);
+	generator |= propagator & shift(generator, offset * 2);
+	propagator &= shift(propagator, offset * 2);
+	generator |= propagator & shift(generator, offset * 4);
+
+	shift(generator, offset) & wrap
+}
+
+//* No lookup tables, every attack is filled from the occupancy */
+impl MoveGenerator {
+	pub(crate) fn kogge_stone_rook(square_index: usize, occupancy: Bitboard) -> Bitboard {
+		let bb_slider = 1 << square_index;
+		let bb_empty = !occupancy;
+
+		ray_attack(bb_slider, bb_empty, NORTH)
+			| ray_attack(bb_slider, bb_empty, SOUTH)
+			| ray_attack(bb_slider, bb_empty, EAST)
+			| ray_attack(bb_slider, bb_empty, WEST)
+	}
+
+	pub(crate) fn kogge_stone_bishop(square_index: usize, occupancy: Bitboard) -> Bitboard {
+		let bb_slider = 1 << square_index;
+		let bb_empty = !occupancy;
+
+		ray_attack(bb_slider, bb_empty, NORTH_EAST)
+			| ray_attack(bb_slider, bb_empty, NORTH_WEST)
+			| ray_attack(bb_slider, bb_empty, SOUTH_EAST)
+			| ray_attack(bb_slider, bb_empty, SOUTH_WEST)
+	}
+}
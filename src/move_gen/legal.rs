@@ -0,0 +1,185 @@
+use super::{
+	defs::{Move, MoveKind},
+	move_list::MoveList,
+	rank_span, MoveGenerator, CASTLE_KING_INDEX, CASTLE_RIGHTS, CASTLE_ROOK_TARGET,
+	PAWN_DOUBLE_PUSH_RANK, PAWN_PROMOTION_RANK, PAWN_PUSH_DIRECTION,
+};
+
+use crate::{board::Board, piece::Piece};
+
+impl MoveGenerator {
+	// Whether the move could be generated in this position if pins and checks are ignored
+	pub fn is_pseudo_legal(&self, board: &Board, to_move: Move) -> bool {
+		let color = board.get_color();
+		let inactive = !color;
+		let color_index = color.to_index();
+
+		let piece = to_move.piece();
+		let start_square = to_move.get_start_square();
+		let target_square = to_move.get_target_square();
+
+		if board.get_piece_at(start_square) != Some((piece, color)) {
+			return false;
+		}
+
+		let kind = to_move.kind();
+
+		let is_promotion_piece = matches!(
+			to_move.promotion(),
+			Some(Piece::Knight | Piece::Bishop | Piece::Rook | Piece::Queen)
+		);
+
+		if is_promotion_piece != (kind == MoveKind::Promotion) {
+			return false;
+		}
+
+		if kind == MoveKind::Castle {
+			return self.is_pseudo_legal_castle(board, to_move);
+		}
+
+		//* The captured piece is part of the move, so it has to be the one on the target square */
+		let captured = board
+			.get_piece_at(target_square)
+			.filter(|&(_, piece_color)| piece_color == inactive)
+			.map(|(piece, _)| piece.to_index());
+
+		if to_move.get_piece_captured() != captured {
+			return false;
+		}
+
+		let bb_target = self.tables.square_bit[target_square as usize];
+		let bb_occupancy = board.get_occupancy();
+		let bb_opponent_pieces = board.get_allys(inactive);
+
+		let is_capture = bb_target & bb_opponent_pieces != 0;
+		let is_empty = bb_target & bb_occupancy == 0;
+
+		if !is_capture && !is_empty {
+			return false;
+		}
+
+		if piece != Piece::Pawn {
+			let bb_attack = match piece {
+				Piece::King | Piece::Knight => {
+					self.get_non_slider_attacks(piece, start_square as usize)
+				}
+				_ => self.get_slider_attacks(piece, start_square as usize, bb_occupancy),
+			};
+
+			return bb_attack & bb_target != 0
+				&& match kind {
+					MoveKind::Quiet => is_empty,
+					MoveKind::Capture => is_capture,
+					_ => false,
+				};
+		}
+
+		let direction = PAWN_PUSH_DIRECTION[color_index];
+		let is_promotion_rank = bb_target & PAWN_PROMOTION_RANK[color_index] != 0;
+
+		let is_push = is_empty && start_square as i8 + direction == target_square as i8;
+		let is_attack = self.get_pawn_attacks(start_square as usize, color) & bb_target != 0;
+
+		match kind {
+			MoveKind::Quiet => is_push && !is_promotion_rank,
+			MoveKind::Capture => is_attack && is_capture && !is_promotion_rank,
+			MoveKind::Promotion => is_promotion_rank && (is_push || is_attack && is_capture),
+			MoveKind::DoublePush => {
+				let middle_square = start_square as i8 + direction;
+				let bb_middle = self.tables.square_bit[middle_square as usize];
+
+				is_empty
+					&& bb_middle & bb_occupancy == 0
+					&& middle_square + direction == target_square as i8
+					&& bb_target & PAWN_DOUBLE_PUSH_RANK[color_index] != 0
+			}
+			MoveKind::EnPassant => {
+				is_attack && board.get_enpassant() == Some(target_square as usize)
+			}
+			MoveKind::Castle => false,
+		}
+	}
+
+	// Whether the move is one of the legal moves of this position
+	pub fn is_legal(&self, board: &Board, to_move: Move) -> bool {
+		if !self.is_pseudo_legal(board, to_move) {
+			return false;
+		}
+
+		let context = self.calculate_attack_mask(board);
+
+		let start_square = to_move.get_start_square() as usize;
+		let bb_target = self.tables.square_bit[to_move.get_target_square() as usize];
+
+		//* Castling and en passant have their own rules, so ask their generators */
+		match to_move.kind() {
+			MoveKind::Castle => {
+				let mut list = MoveList::new();
+				self.castling(board, &context, &mut list);
+
+				return list.contains(&to_move);
+			}
+			MoveKind::EnPassant => {
+				let mut list = MoveList::new();
+
+				if !context.double_check {
+					self.enpassant(board, &context, &mut list);
+				}
+
+				return list.contains(&to_move);
+			}
+			_ => {}
+		}
+
+		if to_move.piece() == Piece::King {
+			return bb_target & context.bb_attack == 0;
+		}
+
+		if context.double_check {
+			return false;
+		}
+
+		let mut bb_moves = bb_target;
+		context.isolate_attack_pin_checks(&mut bb_moves, start_square);
+
+		bb_moves != 0
+	}
+
+	fn is_pseudo_legal_castle(&self, board: &Board, to_move: Move) -> bool {
+		let color = board.get_color();
+		let castle_index = to_move.get_castle_index();
+
+		//* The start square was checked to hold the moving piece, so this is the king's square */
+		if to_move.piece() != Piece::King || to_move.get_piece_captured().is_some() {
+			return false;
+		}
+
+		if castle_index >= 4 || CASTLE_RIGHTS[color.to_index()] & (1 << castle_index) == 0 {
+			return false;
+		}
+
+		if board.get_castle_rights() & (1 << castle_index) == 0 {
+			return false;
+		}
+
+		let king_square = to_move.get_start_square();
+		let rook_square = board.get_castle_rook(castle_index);
+		let king_target = CASTLE_KING_INDEX[castle_index];
+		let rook_target = CASTLE_ROOK_TARGET[castle_index];
+
+		if to_move.get_target_square() != king_target
+			|| board.get_piece_at(rook_square) != Some((Piece::Rook, color))
+		{
+			return false;
+		}
+
+		let bb_castle_pieces = self.tables.square_bit[king_square as usize]
+			| self.tables.square_bit[rook_square as usize];
+
+		let bb_castle_empty = (rank_span(king_square, king_target)
+			| rank_span(rook_square, rook_target))
+			& !bb_castle_pieces;
+
+		board.get_occupancy() & bb_castle_empty == 0
+	}
+}
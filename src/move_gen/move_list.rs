@@ -0,0 +1,121 @@
+use std::ops::{Deref, DerefMut};
+
+use super::defs::Move;
+
+// No legal chess position has more than 218 moves
+pub const MAX_MOVES: usize = 256;
+
+// Clone only, so a ~2 KB list is never copied by accident
+#[derive(Clone)]
+pub struct MoveList {
+	moves: [Move; MAX_MOVES],
+	len: usize,
+}
+
+impl Default for MoveList {
+	fn default() -> Self {
+		Self {
+			moves: [Move::new(0); MAX_MOVES],
+			len: 0,
+		}
+	}
+}
+
+impl MoveList {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn push(&mut self, to_move: Move) {
+		self.moves[self.len] = to_move;
+		self.len += 1;
+	}
+
+	pub fn pop(&mut self) -> Option<Move> {
+		if self.len == 0 {
+			return None;
+		}
+
+		self.len -= 1;
+
+		Some(self.moves[self.len])
+	}
+
+	pub fn swap_remove(&mut self, index: usize) -> Move {
+		assert!(index < self.len, "Index out of bounds: {index}");
+
+		let to_move = self.moves[index];
+
+		self.len -= 1;
+		self.moves[index] = self.moves[self.len];
+
+		to_move
+	}
+
+	pub fn clear(&mut self) {
+		self.len = 0;
+	}
+}
+
+//* Slice access gives len, iter, indexing and sorting */
+impl Deref for MoveList {
+	type Target = [Move];
+
+	fn deref(&self) -> &[Move] {
+		&self.moves[..self.len]
+	}
+}
+
+impl DerefMut for MoveList {
+	fn deref_mut(&mut self) -> &mut [Move] {
+		&mut self.moves[..self.len]
+	}
+}
+
+pub struct IntoIter {
+	list: MoveList,
+	index: usize,
+}
+
+impl Iterator for IntoIter {
+	type Item = Move;
+
+	fn next(&mut self) -> Option<Move> {
+		if self.index == self.list.len {
+			return None;
+		}
+
+		self.index += 1;
+
+		Some(self.list.moves[self.index - 1])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.list.len - self.index;
+
+		(remaining, Some(remaining))
+	}
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for MoveList {
+	type Item = Move;
+	type IntoIter = IntoIter;
+
+	fn into_iter(self) -> IntoIter {
+		IntoIter {
+			list: self,
+			index: 0,
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+	type Item = &'a Move;
+	type IntoIter = std::slice::Iter<'a, Move>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
@@ -2,35 +2,34 @@ use super::MoveGenerator;
 
 use crate::board::Board;
 
+const fn min(a: u8, b: u8) -> u8 {
+	if a < b {
+		a
+	} else {
+		b
+	}
+}
+
 impl MoveGenerator {
-	pub fn rook_num_to_edge(square_index: u8) -> [u8; 4] {
+	pub(crate) const fn rook_num_to_edge(square_index: u8) -> [u8; 4] {
 		let (rank, file) = Board::square_to_rank_file(square_index);
-		let rank = rank as i8;
-		let file = file as i8;
 
-		[
-			u8::max((7 - file) as u8, 0),
-			u8::max((7 - rank) as u8, 0),
-			u8::max(file as u8, 0),
-			u8::max(rank as u8, 0),
-		]
+		[7 - file, 7 - rank, file, rank]
 	}
 
-	pub fn bishop_num_to_edge(square_index: u8) -> [u8; 4] {
+	pub(crate) const fn bishop_num_to_edge(square_index: u8) -> [u8; 4] {
 		let (rank, file) = Board::square_to_rank_file(square_index);
-		let rank = rank as i8;
-		let file = file as i8;
 
-		let num_east = i8::max(7 - file, 0) as u8;
-		let num_north = i8::max(7 - rank, 0) as u8;
-		let num_west = i8::max(file, 0) as u8;
-		let num_south = i8::max(rank, 0) as u8;
+		let num_east = 7 - file;
+		let num_north = 7 - rank;
+		let num_west = file;
+		let num_south = rank;
 
 		[
-			u8::min(num_north, num_west),
-			u8::min(num_north, num_east),
-			u8::min(num_south, num_east),
-			u8::min(num_south, num_west),
+			min(num_north, num_west),
+			min(num_north, num_east),
+			min(num_south, num_east),
+			min(num_south, num_west),
 		]
 	}
 }
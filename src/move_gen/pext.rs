@@ -0,0 +1,17 @@
+use std::arch::x86_64::_pext_u64;
+
+use crate::{bitboard::Bitboard, magic::Magic};
+
+#[target_feature(enable = "bmi2")]
+unsafe fn pext(value: u64, mask: u64) -> u64 {
+	_pext_u64(value, mask)
+}
+
+impl Magic {
+	/// # Safety
+	///
+	/// The CPU must support BMI2, which `SliderBackend::Pext.is_supported()` checks.
+	pub(crate) unsafe fn get_pext_index(&self, blocker: Bitboard) -> usize {
+		(pext(blocker, self.mask) + self.offset) as usize
+	}
+}
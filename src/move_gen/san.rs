@@ -0,0 +1,190 @@
+use super::{
+	defs::{Move, MoveKind},
+	MoveGenerator, Stage,
+};
+
+use crate::{board::Board, color::Color, error::Error, notation::Notation, piece::Piece};
+
+// Castle indices 1 and 3 are the kingside castles
+const KINGSIDE_CASTLE: [bool; 4] = [false, true, false, true];
+
+impl MoveGenerator {
+	// PGN style, which never marks en passant
+	pub fn move_to_san(&self, board: &Board, to_move: Move) -> String {
+		self.san(board, to_move, false)
+	}
+
+	// FIDE style, en passant captures get an " e.p." suffix
+	pub fn move_to_san_with_ep(&self, board: &Board, to_move: Move) -> String {
+		self.san(board, to_move, true)
+	}
+
+	fn san(&self, board: &Board, to_move: Move, enpassant_suffix: bool) -> String {
+		let mut san = String::new();
+
+		if to_move.kind() == MoveKind::Castle {
+			if KINGSIDE_CASTLE[to_move.get_castle_index()] {
+				san.push_str("O-O");
+			} else {
+				san.push_str("O-O-O");
+			}
+		} else {
+			let piece = to_move.piece();
+			let start_square = to_move.get_start_square();
+			let (start_rank, start_file) = Board::square_to_rank_file(start_square);
+			let from = to_move.from().to_string();
+
+			if piece == Piece::Pawn {
+				if to_move.is_capture() {
+					san.push_str(&from[0..1]);
+				}
+			} else {
+				san.push_str(&piece.to_string(Color::White));
+
+				//* Other pieces of the same kind that can reach the same square */
+				let others: Vec<Move> = self
+					.all_possible(board)
+					.into_iter()
+					.filter(|other| {
+						other.piece() == piece
+							&& other.kind() != MoveKind::Castle
+							&& other.to() == to_move.to()
+							&& other.get_start_square() != start_square
+					})
+					.collect();
+
+				if !others.is_empty() {
+					let rank_file =
+						|other: &Move| Board::square_to_rank_file(other.get_start_square());
+
+					if others.iter().all(|other| rank_file(other).1 != start_file) {
+						san.push_str(&from[0..1]);
+					} else if others.iter().all(|other| rank_file(other).0 != start_rank) {
+						san.push_str(&from[1..2]);
+					} else {
+						san.push_str(&from);
+					}
+				}
+			}
+
+			if to_move.is_capture() {
+				san.push('x');
+			}
+
+			san.push_str(&to_move.to().to_string());
+
+			if let Some(promotion) = to_move.promotion() {
+				san.push('=');
+				san.push_str(&promotion.to_string(Color::White));
+			}
+
+			if enpassant_suffix && to_move.kind() == MoveKind::EnPassant {
+				san.push_str(" e.p.");
+			}
+		}
+
+		if self.check_kind(board, to_move).is_some() {
+			//* Mate needs the position after the move, which is played on a copy */
+			let mut child = board.clone();
+			child.make_move(to_move);
+
+			let context = self.calculate_attack_mask(&child);
+
+			if self.generate(&child, &context, Stage::All).is_empty() {
+				san.push('#');
+			} else {
+				san.push('+');
+			}
+		}
+
+		san
+	}
+
+	pub fn parse_san(&self, board: &Board, san: &str) -> Result<Move, Error> {
+		let invalid = || Error::InvalidSanMove {
+			san: san.to_string(),
+		};
+
+		//* Annotations, check marks and the e.p. suffix carry no information */
+		let mut value = san.trim().trim_end_matches(['+', '#', '!', '?']);
+		value = value.trim_end_matches("e.p.").trim_end();
+
+		let moves = self.all_possible(board);
+
+		let castle = value.replace('0', "O").to_ascii_uppercase();
+
+		if castle == "O-O" || castle == "O-O-O" {
+			let kingside = castle == "O-O";
+
+			return moves
+				.into_iter()
+				.find(|to_move| {
+					to_move.kind() == MoveKind::Castle
+						&& KINGSIDE_CASTLE[to_move.get_castle_index()] == kingside
+				})
+				.ok_or_else(|| Error::IllegalMove {
+					notation: san.to_string(),
+				});
+		}
+
+		let mut chars: Vec<char> = value
+			.chars()
+			.filter(|char| !matches!(char, 'x' | 'X' | ':' | '-' | '='))
+			.collect();
+
+		if !chars.iter().all(char::is_ascii_alphanumeric) {
+			return Err(invalid());
+		}
+
+		let piece = match chars.first() {
+			Some(char) if "KQRBNP".contains(*char) => Piece::from(chars.remove(0)),
+			_ => Piece::Pawn,
+		};
+
+		let promotion = match chars.as_slice() {
+			[.., rank, char] if rank.is_ascii_digit() && "qrbnQRBN".contains(*char) => {
+				Some(Piece::from(chars.pop().unwrap()))
+			}
+			_ => None,
+		};
+
+		if chars.len() < 2 || chars.len() > 4 {
+			return Err(invalid());
+		}
+
+		let target: String = chars.split_off(chars.len() - 2).into_iter().collect();
+		let target_square = target.parse::<Notation>().map_err(|_| invalid())?;
+
+		let mut from_file = None;
+		let mut from_rank = None;
+
+		for char in chars {
+			match char {
+				'a'..='h' if from_file.is_none() => from_file = Some(char as u8 - b'a'),
+				'1'..='8' if from_rank.is_none() => from_rank = Some(char as u8 - b'1'),
+				_ => return Err(invalid()),
+			}
+		}
+
+		let mut candidates = moves.into_iter().filter(|to_move| {
+			let (rank, file) = Board::square_to_rank_file(to_move.get_start_square());
+
+			to_move.piece() == piece
+				&& to_move.kind() != MoveKind::Castle
+				&& to_move.to() == target_square
+				&& to_move.promotion() == promotion
+				&& from_file.is_none_or(|from_file| from_file == file)
+				&& from_rank.is_none_or(|from_rank| from_rank == rank)
+		});
+
+		match (candidates.next(), candidates.next()) {
+			(Some(to_move), None) => Ok(to_move),
+			(Some(_), Some(_)) => Err(Error::AmbiguousMove {
+				san: san.to_string(),
+			}),
+			(None, _) => Err(Error::IllegalMove {
+				notation: san.to_string(),
+			}),
+		}
+	}
+}
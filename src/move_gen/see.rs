@@ -0,0 +1,122 @@
+use super::{
+	defs::{Move, MoveKind},
+	MoveGenerator, PAWN_PUSH_DIRECTION,
+};
+
+use crate::{bitboard::get_lsb_index, board::Board, notation::Notation, piece::Piece};
+
+//* Indexed by piece: King, Pawn, Knight, Bishop, Rook, Queen */
+const SEE_VALUES: [i32; 6] = [20_000, 100, 320, 330, 500, 900];
+
+const SEE_ORDER: [Piece; 6] = [
+	Piece::Pawn,
+	Piece::Knight,
+	Piece::Bishop,
+	Piece::Rook,
+	Piece::Queen,
+	Piece::King,
+];
+
+fn value(piece: Piece) -> i32 {
+	SEE_VALUES[piece.to_index()]
+}
+
+impl MoveGenerator {
+	// Material balance of the exchange the move starts on its target square, from the mover's side
+	pub fn see(&self, board: &Board, to_move: Move) -> i32 {
+		if to_move.kind() == MoveKind::Castle {
+			return 0;
+		}
+
+		let mut color = board.get_color();
+
+		let start_square = to_move.get_start_square() as usize;
+		let target_square = to_move.get_target_square() as usize;
+		let target = Notation::from(target_square);
+
+		let mut bb_occupancy = board.get_occupancy();
+		let mut gain = [0; 32];
+
+		gain[0] = match to_move.kind() {
+			MoveKind::EnPassant => {
+				let pawn_square = target_square as i8 - PAWN_PUSH_DIRECTION[color.to_index()];
+				bb_occupancy ^= self.tables.square_bit[pawn_square as usize];
+
+				value(Piece::Pawn)
+			}
+			_ => board
+				.get_piece_at(target_square as u8)
+				.map_or(0, |(piece, _)| value(piece)),
+		};
+
+		let mut piece_on_square = to_move.piece();
+
+		if let Some(promotion) = to_move.promotion() {
+			gain[0] += value(promotion) - value(Piece::Pawn);
+			piece_on_square = promotion;
+		}
+
+		let bb_queens =
+			board.get_bitboard(Piece::Queen, color) | board.get_bitboard(Piece::Queen, !color);
+		let bb_rooks = board.get_bitboard(Piece::Rook, color)
+			| board.get_bitboard(Piece::Rook, !color)
+			| bb_queens;
+		let bb_bishops = board.get_bitboard(Piece::Bishop, color)
+			| board.get_bitboard(Piece::Bishop, !color)
+			| bb_queens;
+
+		let mut bb_attackers = self.attackers_to(board, target, bb_occupancy);
+		let mut bb_from = self.tables.square_bit[start_square];
+
+		let mut depth = 0;
+
+		loop {
+			// Lifting the last capturer may uncover a slider behind it
+			let (rook_xray, _) =
+				self.xray_attack(Piece::Rook, target_square, bb_from, bb_occupancy);
+			let (bishop_xray, _) =
+				self.xray_attack(Piece::Bishop, target_square, bb_from, bb_occupancy);
+
+			bb_occupancy ^= bb_from;
+			bb_attackers |= rook_xray & bb_rooks | bishop_xray & bb_bishops;
+			bb_attackers &= bb_occupancy;
+
+			color = !color;
+
+			let bb_side_attackers = bb_attackers & board.get_allys(color);
+
+			if bb_side_attackers == 0 {
+				break;
+			}
+
+			let attacker = SEE_ORDER
+				.into_iter()
+				.find(|&piece| bb_side_attackers & board.get_bitboard(piece, color) != 0)
+				.unwrap();
+
+			// The king may only capture when nothing can take it back
+			if attacker == Piece::King && bb_attackers & board.get_allys(!color) != 0 {
+				break;
+			}
+
+			depth += 1;
+			gain[depth] = value(piece_on_square) - gain[depth - 1];
+
+			let bb_pieces = bb_side_attackers & board.get_bitboard(attacker, color);
+			bb_from = self.tables.square_bit[get_lsb_index(bb_pieces) as usize];
+			piece_on_square = attacker;
+		}
+
+		//* Either side may stop capturing when going on would lose material */
+		while depth > 0 {
+			gain[depth - 1] = -i32::max(-gain[depth - 1], gain[depth]);
+			depth -= 1;
+		}
+
+		gain[0]
+	}
+
+	pub fn see_ge(&self, board: &Board, to_move: Move, threshold: i32) -> bool {
+		self.see(board, to_move) >= threshold
+	}
+}
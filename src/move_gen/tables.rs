@@ -0,0 +1,40 @@
+use crate::bitboard::Bitboard;
+#[cfg(not(feature = "kogge-stone-only"))]
+use crate::magic::{Magic, BISHOP_TABLE_SIZE, ROOK_TABLE_SIZE};
+
+pub(crate) struct Tables {
+	pub(crate) king: [Bitboard; 64],
+	pub(crate) pawn: [[Bitboard; 64]; 2],
+	pub(crate) knight: [Bitboard; 64],
+	#[cfg(not(feature = "kogge-stone-only"))]
+	pub(crate) sliders: SliderTables,
+
+	pub(crate) square_bit: [u64; 64],
+}
+
+// Left out of table-free builds, where Kogge-Stone is the only backend
+#[cfg(not(feature = "kogge-stone-only"))]
+pub(crate) struct SliderTables {
+	pub(crate) rook: [Bitboard; ROOK_TABLE_SIZE],
+	pub(crate) bishop: [Bitboard; BISHOP_TABLE_SIZE],
+	pub(crate) rook_magics: [Magic; 64],
+	pub(crate) bishop_magics: [Magic; 64],
+
+	//* Every square after the other, ordered by the pext of the blockers */
+	#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+	pub(crate) rook_pext: [Bitboard; 102_400],
+	#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+	pub(crate) bishop_pext: [Bitboard; 5_248],
+	#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+	pub(crate) rook_pext_magics: [Magic; 64],
+	#[cfg(all(feature = "pext", target_arch = "x86_64"))]
+	pub(crate) bishop_pext_magics: [Magic; 64],
+}
+
+// Generated at compile time and shared by every MoveGenerator
+#[allow(long_running_const_eval)]
+static TABLES: Tables = Tables::new();
+
+pub(crate) fn tables() -> &'static Tables {
+	&TABLES
+}
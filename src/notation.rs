@@ -5,8 +5,10 @@ use std::{
 
 use crate::error::Error;
 
+pub type Square = Notation;
+
 #[rustfmt::skip]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Notation {
 	A1, B1, C1, D1, E1, F1, G1, H1,
 	A2, B2, C2, D2, E2, F2, G2, H2,
@@ -242,12 +244,10 @@ impl Display for Notation {
 }
 
 impl Notation {
-	#[allow(dead_code)]
 	pub fn to_index(&self) -> usize {
 		*self as usize
 	}
 
-	#[allow(dead_code)]
 	pub fn to_string(&self) -> String {
 		format!("{}", self)
 	}
@@ -1,7 +1,7 @@
 use crate::color::Color;
 use std::fmt::{Display, Formatter, Result};
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Piece {
 	King,
 	Pawn,
@@ -0,0 +1,105 @@
+pub struct Zobrist {
+	pieces: [[[u64; 64]; 6]; 2],
+	castle_rights: [u64; 16],
+	enpassant: [u64; 8],
+	color: u64,
+}
+
+pub static ZOBRIST: Zobrist = Zobrist::new(0x9e3779b97f4a7c15);
+
+// SplitMix64
+// https://prng.di.unimi.it/splitmix64.c
+pub(crate) const fn splitmix64(state: &mut u64) -> u64 {
+	*state = state.wrapping_add(0x9e3779b97f4a7c15);
+
+	let mut key = *state;
+	key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+	key ^ (key >> 31)
+}
+
+impl Zobrist {
+	const fn new(seed: u64) -> Self {
+		let mut state = seed;
+
+		let mut pieces = [[[0; 64]; 6]; 2];
+		let mut color_index = 0;
+
+		while color_index < 2 {
+			let mut piece_index = 0;
+
+			while piece_index < 6 {
+				let mut square_index = 0;
+
+				while square_index < 64 {
+					pieces[color_index][piece_index][square_index] = splitmix64(&mut state);
+					square_index += 1;
+				}
+
+				piece_index += 1;
+			}
+
+			color_index += 1;
+		}
+
+		//* Every combination of rights is the xor of its single rights, so no rights hash to 0 */
+		let mut castle_rights = [0; 16];
+		let single_rights = [
+			splitmix64(&mut state),
+			splitmix64(&mut state),
+			splitmix64(&mut state),
+			splitmix64(&mut state),
+		];
+
+		let mut rights = 0;
+
+		while rights < 16 {
+			let mut castle_index = 0;
+
+			while castle_index < 4 {
+				if rights & (1 << castle_index) != 0 {
+					castle_rights[rights] ^= single_rights[castle_index];
+				}
+
+				castle_index += 1;
+			}
+
+			rights += 1;
+		}
+
+		let mut enpassant = [0; 8];
+		let mut file = 0;
+
+		while file < 8 {
+			enpassant[file] = splitmix64(&mut state);
+			file += 1;
+		}
+
+		Self {
+			pieces,
+			castle_rights,
+			enpassant,
+			color: splitmix64(&mut state),
+		}
+	}
+
+	pub fn piece(&self, piece: usize, color: usize, square_index: u8) -> u64 {
+		self.pieces[color][piece][square_index as usize]
+	}
+
+	pub fn castle_rights(&self, castle_rights: usize) -> u64 {
+		self.castle_rights[castle_rights]
+	}
+
+	pub fn enpassant(&self, enpassant: Option<usize>) -> u64 {
+		match enpassant {
+			Some(square_index) => self.enpassant[square_index % 8],
+			None => 0,
+		}
+	}
+
+	pub fn color(&self) -> u64 {
+		self.color
+	}
+}
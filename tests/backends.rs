@@ -0,0 +1,110 @@
+#![cfg(not(feature = "kogge-stone-only"))]
+
+use chess_rust_old::{Board, Color, MoveGenerator, SliderBackend, Square};
+
+const POSITIONS: [(&str, usize, usize); 6] = [
+	(
+		"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+		3,
+		8_902,
+	),
+	(
+		"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+		3,
+		97_862,
+	),
+	("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4, 43_238),
+	(
+		"r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+		3,
+		9_467,
+	),
+	(
+		"rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+		3,
+		62_379,
+	),
+	(
+		"r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+		3,
+		89_890,
+	),
+];
+
+// Perft driven by the other backend, every node's attack sets are checked against magics
+fn perft(board: &mut Board, magic: &MoveGenerator, other: &MoveGenerator, depth: usize) -> usize {
+	let fen = board.to_fen();
+
+	for color in [Color::White, Color::Black] {
+		assert_eq!(
+			magic.attack_map(board, color),
+			other.attack_map(board, color),
+			"{fen}"
+		);
+	}
+
+	for square_index in 0..64 {
+		let square = Square::from(square_index);
+		let occupancy = board.get_occupancy();
+
+		assert_eq!(
+			magic.attackers_to(board, square, occupancy),
+			other.attackers_to(board, square, occupancy),
+			"{fen}"
+		);
+	}
+
+	let moves = other.all_possible(board);
+	assert_eq!(moves.len(), magic.all_possible(board).len(), "{fen}");
+
+	if depth == 1 {
+		return moves.len();
+	}
+
+	let mut nodes = 0;
+
+	for to_move in moves {
+		board.make_move(to_move);
+		nodes += perft(board, magic, other, depth - 1);
+		board.undo_move();
+	}
+
+	nodes
+}
+
+#[test]
+fn backends_match_magic() {
+	let magic = MoveGenerator::with_backend(SliderBackend::Magic).unwrap();
+
+	for backend in SliderBackend::available() {
+		let other = MoveGenerator::with_backend(backend).unwrap();
+
+		for (fen, depth, nodes) in POSITIONS {
+			let mut board: Board = fen.parse().unwrap();
+
+			assert_eq!(
+				perft(&mut board, &magic, &other, depth),
+				nodes,
+				"{backend:?} {fen}"
+			);
+		}
+	}
+}
+
+#[test]
+fn pext_is_declared_without_the_feature() {
+	let supported = SliderBackend::Pext.is_supported();
+
+	if !cfg!(feature = "pext") {
+		assert!(!supported);
+	}
+
+	assert_eq!(
+		SliderBackend::available().contains(&SliderBackend::Pext),
+		supported
+	);
+	assert_eq!(
+		MoveGenerator::with_backend(SliderBackend::Pext).is_ok(),
+		supported
+	);
+}
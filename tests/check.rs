@@ -0,0 +1,136 @@
+use chess_rust_old::{Board, CheckKind, MoveGenerator};
+
+fn check_kind(fen: &str, uci: &str) -> Option<CheckKind> {
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+	let to_move = move_gen.parse_uci(&board, uci).unwrap();
+
+	assert_eq!(
+		move_gen.gives_check(&board, to_move),
+		move_gen.check_kind(&board, to_move).is_some()
+	);
+
+	move_gen.check_kind(&board, to_move)
+}
+
+#[test]
+fn direct_discovered_and_double_checks() {
+	let knight = "4k3/8/8/8/4N3/8/8/4R1K1 w - - 0 1";
+
+	assert_eq!(check_kind(knight, "e4c3"), Some(CheckKind::Discovered));
+	assert_eq!(check_kind(knight, "e4d6"), Some(CheckKind::Double));
+	assert_eq!(check_kind(knight, "e4f6"), Some(CheckKind::Double));
+	assert_eq!(check_kind(knight, "g1g2"), None);
+	assert_eq!(check_kind(knight, "e1f1"), None);
+
+	assert_eq!(
+		check_kind(
+			"r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+			"h5f7"
+		),
+		Some(CheckKind::Direct)
+	);
+
+	//* The king steps out of a bishop's line */
+	assert_eq!(
+		check_kind("7k/8/8/8/8/8/1K6/B7 w - - 0 1", "b2c2"),
+		Some(CheckKind::Discovered)
+	);
+}
+
+#[test]
+fn castling_rook_gives_check() {
+	assert_eq!(
+		check_kind("3k4/8/8/8/8/8/8/R3K3 w Q - 0 1", "e1c1"),
+		Some(CheckKind::Direct)
+	);
+	assert_eq!(
+		check_kind("5k2/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1"),
+		Some(CheckKind::Direct)
+	);
+
+	//* The rook only sees the king through the square the king left */
+	assert_eq!(
+		check_kind("8/8/8/8/8/8/8/k3K2R w K - 0 1", "e1g1"),
+		Some(CheckKind::Direct)
+	);
+	assert_eq!(check_kind("4k3/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1"), None);
+}
+
+#[test]
+fn en_passant_checks() {
+	assert_eq!(
+		check_kind("8/4k3/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"),
+		Some(CheckKind::Direct)
+	);
+
+	//* Both pawns leave the rank between rook and king */
+	assert_eq!(
+		check_kind("8/8/8/k2pP2R/8/8/8/4K3 w - d6 0 1", "e5d6"),
+		Some(CheckKind::Discovered)
+	);
+
+	//* The bishop behind the captured pawn only checks when the king is on its diagonal */
+	assert_eq!(
+		check_kind("8/8/8/3pP3/8/5B2/8/k3K3 w - d6 0 1", "e5d6"),
+		None
+	);
+	assert_eq!(
+		check_kind("8/1k6/8/3pP3/8/5B2/8/4K3 w - d6 0 1", "e5d6"),
+		Some(CheckKind::Discovered)
+	);
+}
+
+#[test]
+fn promotion_checks() {
+	assert_eq!(
+		check_kind("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8q"),
+		Some(CheckKind::Direct)
+	);
+	assert_eq!(check_kind("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8b"), None);
+	assert_eq!(
+		check_kind("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1", "e7e8n"),
+		Some(CheckKind::Direct)
+	);
+	assert_eq!(check_kind("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1", "e7e8q"), None);
+	assert_eq!(
+		check_kind("8/R3P2k/8/8/8/8/8/4K3 w - - 0 1", "e7e8r"),
+		Some(CheckKind::Discovered)
+	);
+	assert_eq!(
+		check_kind("7k/R3P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8q"),
+		Some(CheckKind::Direct)
+	);
+}
+
+#[test]
+fn agrees_with_making_the_move() {
+	let fens = [
+		"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+		"8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+		"r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+		"rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+		"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+	];
+
+	let move_gen = MoveGenerator::default();
+
+	for fen in fens {
+		let mut board: Board = fen.parse().unwrap();
+
+		for to_move in move_gen.all_possible(&board) {
+			let check_kind = move_gen.check_kind(&board, to_move);
+
+			board.make_move(to_move);
+			let context = move_gen.calculate_attack_mask(&board);
+			board.undo_move();
+
+			assert_eq!(check_kind.is_some(), context.is_check(), "{fen} {to_move}");
+			assert_eq!(
+				check_kind == Some(CheckKind::Double),
+				context.is_double_check(),
+				"{fen} {to_move}"
+			);
+		}
+	}
+}
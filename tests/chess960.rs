@@ -0,0 +1,158 @@
+use chess_rust_old::{Benchmark, Board, MoveGenerator, MoveKind};
+
+// Reference counts from the Chess960 perft suite
+const SHREDDER_POSITIONS: [(&str, [usize; 3]); 6] = [
+	(
+		"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+		[21, 528, 12_189],
+	),
+	(
+		"2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9",
+		[21, 807, 18_002],
+	),
+	(
+		"b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9",
+		[20, 479, 10_471],
+	),
+	(
+		"qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9",
+		[22, 593, 13_440],
+	),
+	(
+		"1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9",
+		[28, 1_120, 31_058],
+	),
+	(
+		"qnbnr1kr/ppp1b1pp/4p3/3p1p2/8/2NPP3/PPP1BPPP/QNB1R1KR w HEhe - 1 9",
+		[29, 899, 26_578],
+	),
+];
+
+// The same positions with X-FEN rights, every castling rook is the outermost one
+const XFEN_POSITIONS: [(&str, [usize; 3]); 3] = [
+	(
+		"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+		[21, 528, 12_189],
+	),
+	(
+		"2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w KQkq - 1 9",
+		[21, 807, 18_002],
+	),
+	(
+		"qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w kq - 0 9",
+		[22, 593, 13_440],
+	),
+];
+
+fn perft(fen: &str, depth: usize) -> usize {
+	let mut bench = Benchmark::default();
+	bench.set_fen(fen);
+
+	bench.perft(depth).nodes
+}
+
+#[test]
+fn shredder_fen_perft() {
+	for (fen, counts) in SHREDDER_POSITIONS {
+		for (depth, nodes) in (1..).zip(counts) {
+			assert_eq!(perft(fen, depth), nodes, "{fen} depth {depth}");
+		}
+	}
+}
+
+#[test]
+fn xfen_perft() {
+	for (fen, counts) in XFEN_POSITIONS {
+		for (depth, nodes) in (1..).zip(counts) {
+			assert_eq!(perft(fen, depth), nodes, "{fen} depth {depth}");
+		}
+	}
+}
+
+#[test]
+fn king_and_rook_swap_places() {
+	//* Counted by hand: king 4, rook 8, castling 1 / king 4, rook 12, castling 1 */
+	let cases = [
+		(
+			"k7/8/8/8/8/8/8/5KR1 w G - 0 1",
+			13,
+			"f1g1",
+			"k7/8/8/8/8/8/8/5RK1 b - - 1 1",
+		),
+		(
+			"k7/8/8/8/8/8/8/5KR1 w K - 0 1",
+			13,
+			"f1g1",
+			"k7/8/8/8/8/8/8/5RK1 b - - 1 1",
+		),
+		(
+			"5kr1/8/8/8/8/8/8/K7 b g - 0 1",
+			13,
+			"f8g8",
+			"5rk1/8/8/8/8/8/8/K7 w - - 1 2",
+		),
+		(
+			"k7/8/8/8/8/8/8/5RK1 w F - 0 1",
+			17,
+			"g1f1",
+			"k7/8/8/8/8/8/8/2KR4 b - - 1 1",
+		),
+	];
+
+	let move_gen = MoveGenerator::default();
+
+	for (fen, nodes, uci, after) in cases {
+		let mut board: Board = fen.parse().unwrap();
+
+		assert_eq!(perft(fen, 1), nodes, "{fen}");
+
+		let castle = move_gen.parse_uci(&board, uci).unwrap();
+		assert_eq!(castle.kind(), MoveKind::Castle, "{fen}");
+		assert_eq!(board.move_to_uci(castle), uci, "{fen}");
+
+		board.make_move(castle);
+		assert_eq!(board.to_fen(), after);
+
+		board.undo_move();
+		assert_eq!(board.to_fen(), fen);
+	}
+}
+
+#[test]
+fn chess960_castling_is_king_takes_rook() {
+	let move_gen = MoveGenerator::default();
+	let mut board: Board = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1".parse().unwrap();
+
+	assert!(!board.is_chess960());
+
+	let kingside = move_gen.parse_uci(&board, "e1g1").unwrap();
+	assert_eq!(kingside.kind(), MoveKind::Castle);
+	assert_eq!(board.move_to_uci(kingside), "e1g1");
+
+	board.set_chess960(true);
+
+	assert_eq!(board.move_to_uci(kingside), "e1h1");
+	assert_eq!(move_gen.parse_uci(&board, "e1h1").unwrap(), kingside);
+
+	let queenside = move_gen.parse_uci(&board, "e1a1").unwrap();
+	assert_eq!(queenside.kind(), MoveKind::Castle);
+	assert_eq!(board.move_to_uci(queenside), "e1a1");
+}
+
+#[test]
+fn chess960_fen_round_trips() {
+	let fens = [
+		"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1",
+		"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+		"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+		"qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w kq - 0 9",
+		"k7/8/8/8/8/8/8/5KR1 w G - 0 1",
+	];
+
+	for fen in fens {
+		let board: Board = fen.parse().unwrap();
+
+		assert!(board.is_chess960(), "{fen}");
+		assert_eq!(board.to_fen(), fen);
+	}
+}
@@ -0,0 +1,73 @@
+use chess_rust_old::{Board, MoveGenerator};
+
+// Each move with the FEN it leads to
+const GAME: [(&str, &str); 6] = [
+	(
+		"e2e4",
+		"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+	),
+	(
+		"g8f6",
+		"rnbqkb1r/pppppppp/5n2/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 1 2",
+	),
+	(
+		"g1f3",
+		"rnbqkb1r/pppppppp/5n2/8/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 2 2",
+	),
+	(
+		"f6e4",
+		"rnbqkb1r/pppppppp/8/8/4n3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 0 3",
+	),
+	(
+		"b1c3",
+		"rnbqkb1r/pppppppp/8/8/4n3/2N2N2/PPPP1PPP/R1BQKB1R b KQkq - 1 3",
+	),
+	(
+		"d7d6",
+		"rnbqkb1r/ppp1pppp/3p4/8/4n3/2N2N2/PPPP1PPP/R1BQKB1R w KQkq - 0 4",
+	),
+];
+
+#[test]
+fn make_and_undo_keep_the_counters() {
+	let move_gen = MoveGenerator::default();
+	let mut board = Board::default();
+	let mut fens = vec![board.to_fen()];
+
+	//* Pawn moves and captures reset the halfmove clock, Black's moves end a full move */
+	for (uci, fen) in GAME {
+		let to_move = move_gen.parse_uci(&board, uci).unwrap();
+		board.make_move(to_move);
+
+		assert_eq!(board.to_fen(), fen, "{uci}");
+		fens.push(fen.to_string());
+	}
+
+	fens.pop();
+
+	while let Some(fen) = fens.pop() {
+		assert!(board.undo_move());
+		assert_eq!(board.to_fen(), fen);
+	}
+
+	assert!(!board.undo_move());
+}
+
+#[test]
+fn undo_restores_counters_from_fen() {
+	let move_gen = MoveGenerator::default();
+	let fen = "4k3/8/8/8/8/8/4P3/R3K3 b Q - 37 58";
+	let mut board: Board = fen.parse().unwrap();
+
+	let to_move = move_gen.parse_uci(&board, "e8d7").unwrap();
+	board.make_move(to_move);
+	assert_eq!(board.to_fen(), "8/3k4/8/8/8/8/4P3/R3K3 w Q - 38 59");
+
+	let to_move = move_gen.parse_uci(&board, "e2e4").unwrap();
+	board.make_move(to_move);
+	assert_eq!(board.to_fen(), "8/3k4/8/8/4P3/8/8/R3K3 b Q e3 0 59");
+
+	board.undo_move();
+	board.undo_move();
+	assert_eq!(board.to_fen(), fen);
+}
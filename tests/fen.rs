@@ -0,0 +1,226 @@
+use chess_rust_old::{Board, Color, Error, Piece, Square};
+
+fn parse(fen: &str) -> Result<Board, Error> {
+	fen.parse()
+}
+
+#[test]
+fn rejects_bad_rank_counts_and_lengths() {
+	assert!(matches!(
+		parse("8/8/8/8/8/8/8/8/4K2k w - - 0 1"),
+		Err(Error::InvalidFenRankCount { count: 9 })
+	));
+	assert!(matches!(
+		parse("4k3/8/8/8/8/8/8 w - - 0 1"),
+		Err(Error::InvalidFenRankCount { count: 7 })
+	));
+	assert!(matches!(
+		parse("rnbqkbnrp/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+		Err(Error::InvalidFenRankLength { rank: 8, length: 9 })
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/44p/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+		Err(Error::InvalidFenRankLength { rank: 4, length: 9 })
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/7/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+		Err(Error::InvalidFenRankLength { rank: 4, length: 7 })
+	));
+}
+
+#[test]
+fn rejects_missing_or_extra_kings() {
+	assert!(matches!(
+		parse("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+		Err(Error::InvalidFenKingCount {
+			color: Color::Black,
+			count: 0
+		})
+	));
+	assert!(matches!(
+		parse("4k3/8/8/8/8/8/8/3KK3 w - - 0 1"),
+		Err(Error::InvalidFenKingCount {
+			color: Color::White,
+			count: 2
+		})
+	));
+}
+
+#[test]
+fn rejects_back_rank_pawns() {
+	assert!(matches!(
+		parse("4k2P/8/8/8/8/8/8/4K3 w - - 0 1"),
+		Err(Error::InvalidFenPawnRank { square }) if square == "h8"
+	));
+	assert!(matches!(
+		parse("4k3/8/8/8/8/8/8/p3K3 w - - 0 1"),
+		Err(Error::InvalidFenPawnRank { square }) if square == "a1"
+	));
+}
+
+#[test]
+fn rejects_castling_rights_without_pieces() {
+	assert!(matches!(
+		parse("4k3/8/8/8/8/8/8/4K3 w K - 0 1"),
+		Err(Error::InvalidFenCastleRight { right: 'K' })
+	));
+	assert!(matches!(
+		parse("r3k3/8/8/8/8/8/8/4K3 w k - 0 1"),
+		Err(Error::InvalidFenCastleRight { right: 'k' })
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkqX - 0 1"),
+		Err(Error::InvalidFenChar {
+			field: "castling rights",
+			char: 'X'
+		})
+	));
+}
+
+#[test]
+fn rejects_bad_or_impossible_enpassant_squares() {
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e9 0 1"),
+		Err(Error::InvalidFenField {
+			field: "en passant square",
+			..
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1"),
+		Err(Error::InvalidFenEnpassant { square }) if square == "e6"
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e3 0 1"),
+		Err(Error::InvalidFenEnpassant { square }) if square == "e3"
+	));
+}
+
+#[test]
+fn rejects_side_not_to_move_in_check() {
+	assert!(matches!(
+		parse("4k3/4R3/8/8/8/8/8/4K3 w - - 0 1"),
+		Err(Error::InvalidFenOpponentInCheck {
+			color: Color::Black
+		})
+	));
+	assert!(parse("4k3/4R3/8/8/8/8/8/4K3 b - - 0 1").is_ok());
+}
+
+#[test]
+fn rejects_non_ascii_input() {
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNé w KQkq - 0 1"),
+		Err(Error::InvalidFenChar {
+			field: "piece placement",
+			char: 'é'
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR ẃ KQkq - 0 1"),
+		Err(Error::InvalidFenField {
+			field: "side to move",
+			..
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkÄ - 0 1"),
+		Err(Error::InvalidFenChar {
+			field: "castling rights",
+			char: 'Ä'
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq é3 0 1"),
+		Err(Error::InvalidFenField {
+			field: "en passant square",
+			..
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - ½ 1"),
+		Err(Error::InvalidFenField {
+			field: "halfmove clock",
+			..
+		})
+	));
+}
+
+#[test]
+fn rejects_bad_move_counters() {
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1"),
+		Err(Error::InvalidFenField {
+			field: "halfmove clock",
+			..
+		})
+	));
+	assert!(matches!(
+		parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 0"),
+		Err(Error::InvalidFenField {
+			field: "fullmove number",
+			..
+		})
+	));
+}
+
+#[test]
+fn round_trips_every_field() {
+	let fens = [
+		"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+		"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+		"rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+		"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+		"r3k2r/8/8/8/8/8/8/R3K2R b Kq - 37 112",
+		"8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 99 50",
+		"4k3/8/8/8/8/8/8/4K3 b - - 0 1",
+	];
+
+	for fen in fens {
+		assert_eq!(parse(fen).unwrap().to_fen(), fen);
+	}
+}
+
+#[test]
+fn four_field_fen_gets_default_counters() {
+	let board = parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -").unwrap();
+
+	assert_eq!(
+		board.to_fen(),
+		"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+	);
+}
+
+#[test]
+fn piece_at_reads_the_placement() {
+	let board = parse("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
+
+	assert_eq!(
+		board.piece_at(Square::E4),
+		Some((Piece::Pawn, Color::White))
+	);
+	assert_eq!(
+		board.piece_at(Square::D8),
+		Some((Piece::Queen, Color::Black))
+	);
+	assert_eq!(board.piece_at(Square::E2), None);
+}
+
+#[test]
+fn state_is_read_through_accessors() {
+	let board = parse("r3k3/8/8/3pP3/8/8/8/4K2R w Kq d6 12 34").unwrap();
+
+	assert!(board.has_kingside_castle_right(Color::White));
+	assert!(!board.has_queenside_castle_right(Color::White));
+	assert!(!board.has_kingside_castle_right(Color::Black));
+	assert!(board.has_queenside_castle_right(Color::Black));
+	assert_eq!(board.enpassant(), Some(Square::D6));
+	assert_eq!(board.halfmove_clock(), 12);
+	assert_eq!(board.fullmove_number(), 34);
+
+	let board = Board::default();
+
+	assert_eq!(board.enpassant(), None);
+	assert_eq!(board.halfmove_clock(), 0);
+	assert_eq!(board.fullmove_number(), 1);
+}
@@ -0,0 +1,101 @@
+use chess_rust_old::{Board, GameStatus, MoveGenerator};
+
+fn status(fen: &str) -> GameStatus {
+	let board: Board = fen.parse().unwrap();
+
+	MoveGenerator::default().game_status(&board)
+}
+
+fn status_after(moves: &[&str]) -> GameStatus {
+	let move_gen = MoveGenerator::default();
+	let mut board = Board::default();
+
+	for uci in moves {
+		let to_move = move_gen.parse_uci(&board, uci).unwrap();
+		board.make_move(to_move);
+	}
+
+	move_gen.game_status(&board)
+}
+
+#[test]
+fn ongoing() {
+	let status = status("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+
+	assert_eq!(status, GameStatus::Ongoing);
+	assert!(!status.is_over());
+	assert!(!status.is_claimable());
+}
+
+#[test]
+fn checkmate() {
+	let status = status("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3");
+
+	assert_eq!(status, GameStatus::Checkmate);
+	assert!(status.is_over());
+}
+
+#[test]
+fn stalemate() {
+	let status = status("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1");
+
+	assert_eq!(status, GameStatus::Stalemate);
+	assert!(status.is_over());
+}
+
+#[test]
+fn fifty_move_rule_can_be_claimed() {
+	let status = status("4k3/8/8/8/8/8/4P3/R3K3 w - - 100 80");
+
+	assert_eq!(status, GameStatus::FiftyMoveRule);
+	assert!(!status.is_over());
+	assert!(status.is_claimable());
+}
+
+#[test]
+fn seventy_five_move_rule() {
+	let status = status("4k3/8/8/8/8/8/4P3/R3K3 w - - 150 105");
+
+	assert_eq!(status, GameStatus::SeventyFiveMoveRule);
+	assert!(status.is_over());
+}
+
+#[test]
+fn insufficient_material() {
+	assert_eq!(
+		status("4k3/8/8/8/8/8/8/4K3 w - - 0 1"),
+		GameStatus::InsufficientMaterial
+	);
+	assert_eq!(
+		status("4k3/8/8/8/8/8/8/3NK3 w - - 0 1"),
+		GameStatus::InsufficientMaterial
+	);
+	assert_eq!(
+		status("4kb2/8/8/8/8/8/8/2B1K3 w - - 0 1"),
+		GameStatus::InsufficientMaterial
+	);
+	assert_eq!(
+		status("4k3/8/8/8/8/8/8/2NNK3 w - - 0 1"),
+		GameStatus::Ongoing
+	);
+}
+
+#[test]
+fn threefold_repetition_can_be_claimed() {
+	let status = status_after(&[
+		"g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8",
+	]);
+
+	assert_eq!(status, GameStatus::ThreefoldRepetition);
+	assert!(!status.is_over());
+	assert!(status.is_claimable());
+}
+
+#[test]
+fn fivefold_repetition() {
+	let dance = ["g1f3", "g8f6", "f3g1", "f6g8"];
+	let status = status_after(&dance.repeat(4));
+
+	assert_eq!(status, GameStatus::FivefoldRepetition);
+	assert!(status.is_over());
+}
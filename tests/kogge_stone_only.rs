@@ -0,0 +1,22 @@
+#![cfg(feature = "kogge-stone-only")]
+
+use chess_rust_old::{Board, MoveGenerator, SliderBackend};
+
+#[test]
+fn kogge_stone_is_the_only_backend() {
+	assert_eq!(SliderBackend::available(), [SliderBackend::KoggeStone]);
+	assert_eq!(SliderBackend::detect(), SliderBackend::KoggeStone);
+	assert!(MoveGenerator::with_backend(SliderBackend::Magic).is_err());
+	assert!(MoveGenerator::with_backend(SliderBackend::Pext).is_err());
+}
+
+#[test]
+fn default_generator_runs_without_tables() {
+	let move_gen = MoveGenerator::default();
+	let board: Board = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"
+		.parse()
+		.unwrap();
+
+	assert_eq!(move_gen.backend(), SliderBackend::KoggeStone);
+	assert_eq!(move_gen.all_possible(&board).len(), 48);
+}
@@ -0,0 +1,178 @@
+use chess_rust_old::{Board, Move, MoveGenerator};
+
+fn find(fen: &str, uci: &str) -> Move {
+	let board: Board = fen.parse().unwrap();
+
+	MoveGenerator::default().parse_uci(&board, uci).unwrap()
+}
+
+#[test]
+fn pinned_pawn_takes_en_passant_along_the_pin() {
+	let fen = "rnk2r1b/2pp1bp1/pp1n2q1/P2Ppp1p/3NPB2/5P2/KPP3PP/R2RN1QB w q e6 0 17";
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+
+	let to_move = move_gen.parse_uci(&board, "d5e6").unwrap();
+
+	assert!(move_gen.is_pseudo_legal(&board, to_move));
+	assert!(move_gen.is_legal(&board, to_move));
+	assert!(move_gen.all_possible(&board).contains(&to_move));
+
+	//* Pinned across the en passant square, the capture leaves the pin ray */
+	let fen = "b3k3/8/8/3Pp3/8/8/8/7K w - e6 0 1";
+	let board: Board = fen.parse().unwrap();
+	let to_move = find("4k3/8/8/3Pp3/8/8/8/K7 w - e6 0 1", "d5e6");
+
+	assert!(move_gen.is_pseudo_legal(&board, to_move));
+	assert!(!move_gen.is_legal(&board, to_move));
+}
+
+#[test]
+fn rejects_a_moved_or_replaced_piece() {
+	let move_gen = MoveGenerator::default();
+	let knight_move = find(
+		"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+		"g1f3",
+	);
+
+	let board: Board = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBBR w KQkq - 0 1"
+		.parse()
+		.unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, knight_move));
+	assert!(!move_gen.is_legal(&board, knight_move));
+
+	let board: Board = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"
+		.parse()
+		.unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, knight_move));
+}
+
+#[test]
+fn rejects_a_blocked_slider() {
+	let move_gen = MoveGenerator::default();
+	let rook_move = find("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", "a1a4");
+
+	let board: Board = "4k3/8/8/8/8/8/P7/R3K3 w - - 0 1".parse().unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, rook_move));
+	assert!(!move_gen.is_legal(&board, rook_move));
+
+	let board: Board = "4k3/8/8/8/n7/8/8/R3K3 w - - 0 1".parse().unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, rook_move));
+}
+
+#[test]
+fn rejects_castling_through_check() {
+	let move_gen = MoveGenerator::default();
+	let castle = find("4k3/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1");
+
+	let board: Board = "4k3/8/8/8/8/8/5r2/4K2R w K - 0 1".parse().unwrap();
+	assert!(move_gen.is_pseudo_legal(&board, castle));
+	assert!(!move_gen.is_legal(&board, castle));
+
+	let board: Board = "4k3/8/8/8/8/8/8/4KN1R w K - 0 1".parse().unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, castle));
+
+	let board: Board = "4k3/8/8/8/8/8/8/4K2R w - - 0 1".parse().unwrap();
+	assert!(!move_gen.is_pseudo_legal(&board, castle));
+}
+
+#[test]
+fn rejects_a_promotion_flag_on_a_non_promoting_move() {
+	let move_gen = MoveGenerator::default();
+	let board: Board = "k7/8/4P3/8/8/8/8/4K3 w - - 0 1".parse().unwrap();
+
+	let push = move_gen.parse_uci(&board, "e6e7").unwrap();
+	let promotion = find("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8q");
+
+	assert!(move_gen.is_legal(&board, Move::from_bits(push.to_bits()).unwrap()));
+
+	//* The squares of the push with the kind and promotion of the other, like a corrupted hash entry */
+	let squares = 0x7ff8;
+	let corrupted =
+		Move::from_bits(push.to_bits() & squares | promotion.to_bits() & !squares).unwrap();
+
+	assert_eq!(corrupted.to_string(), "e6e7q");
+	assert!(!move_gen.is_pseudo_legal(&board, corrupted));
+	assert!(!move_gen.is_legal(&board, corrupted));
+}
+
+#[test]
+fn accepts_every_generated_move() {
+	let move_gen = MoveGenerator::default();
+	let board: Board = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"
+		.parse()
+		.unwrap();
+
+	for to_move in move_gen.all_possible(&board) {
+		let restored = Move::from_bits(to_move.to_bits()).unwrap();
+
+		assert!(move_gen.is_legal(&board, to_move), "{to_move}");
+		assert!(move_gen.is_legal(&board, restored), "{to_move}");
+		assert_eq!(restored, to_move);
+	}
+}
+
+#[test]
+fn restored_captures_keep_the_captured_piece() {
+	let move_gen = MoveGenerator::default();
+	let board: Board = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1".parse().unwrap();
+
+	let capture = move_gen.parse_uci(&board, "e4d5").unwrap();
+	let restored = Move::from_bits(capture.to_bits()).unwrap();
+
+	assert!(restored.is_capture());
+	assert_eq!(restored, capture);
+	assert!(move_gen.all_possible(&board).contains(&restored));
+	assert_eq!(move_gen.move_to_san(&board, restored), "exd5");
+
+	//* The same squares with the captured piece dropped no longer match the board */
+	let dropped = Move::from_bits(capture.to_bits() & 0x1ffffff).unwrap();
+
+	assert!(!move_gen.is_pseudo_legal(&board, dropped));
+	assert!(!move_gen.is_legal(&board, dropped));
+}
+
+#[test]
+fn rejects_castling_with_a_rook() {
+	let move_gen = MoveGenerator::default();
+	let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+	let board: Board = fen.parse().unwrap();
+
+	//* The rook on a1 with the kind and index of white's queenside castle */
+	let forged = Move::from_bits(4 | 0 << 3 | 2 << 9 | 4 << 15).unwrap();
+
+	assert!(!move_gen.is_pseudo_legal(&board, forged));
+	assert!(!move_gen.is_legal(&board, forged));
+	assert_eq!(board.to_fen(), fen);
+}
+
+#[test]
+fn rejects_a_pawn_as_promotion() {
+	let move_gen = MoveGenerator::default();
+	let board: Board = "8/P6k/8/8/8/8/8/K7 w - - 0 1".parse().unwrap();
+
+	let queen = move_gen.parse_uci(&board, "a7a8q").unwrap();
+	assert!(move_gen.is_legal(&board, queen));
+
+	//* Promotion bits of 1 name the pawn */
+	let pawn = queen.to_bits() & !(0x7 << 18) | 1 << 18;
+
+	assert!(Move::from_bits(pawn).is_none());
+}
+
+#[test]
+fn rejects_bits_without_a_piece_or_kind() {
+	let board: Board = "4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1".parse().unwrap();
+	let rook_move = MoveGenerator::default().parse_uci(&board, "a1a4").unwrap();
+	let bits = rook_move.to_bits();
+
+	for invalid in [6, 7] {
+		assert!(Move::from_bits(bits & !0x7 | invalid).is_none());
+		assert!(Move::from_bits(bits & !(0x7 << 15) | invalid << 15).is_none());
+		assert!(Move::from_bits(bits | invalid << 18).is_none());
+		assert!(Move::from_bits(bits | invalid << 25).is_none());
+	}
+
+	assert!(Move::from_bits(bits | 4 << 21).is_none());
+	assert!(Move::from_bits(bits | 1 << 28).is_none());
+}
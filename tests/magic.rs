@@ -0,0 +1,106 @@
+#![cfg(feature = "magic-search")]
+
+use chess_rust_old::{MagicOptions, MagicTable, Piece};
+
+#[test]
+fn search_is_reproducible() {
+	let options = MagicOptions {
+		seed: 1,
+		..Default::default()
+	};
+
+	let table = MagicTable::search(Piece::Bishop, &options).unwrap();
+	let again = MagicTable::search(Piece::Bishop, &options).unwrap();
+
+	//* Without overlap a1 is searched first, its number only depends on the seed */
+	assert_eq!(table.magics[0].nr, 0x4002141002084100);
+	assert_eq!(table.size, 5_248);
+
+	for (magic, other) in table.magics.iter().zip(&again.magics) {
+		assert_eq!((magic.nr, magic.offset), (other.nr, other.offset));
+	}
+}
+
+#[test]
+fn overlapping_shrinks_the_table() {
+	let options = MagicOptions {
+		seed: 1,
+		overlap: true,
+		candidates: 4,
+		..Default::default()
+	};
+
+	let table = MagicTable::search(Piece::Bishop, &options).unwrap();
+
+	table.verify().unwrap();
+	assert!(table.size < 5_248, "{}", table.size);
+}
+
+#[test]
+fn fixed_bits_must_cover_every_mask() {
+	let options = MagicOptions {
+		fixed_bits: Some(11),
+		..Default::default()
+	};
+
+	assert!(MagicTable::search(Piece::Rook, &options).is_err());
+}
+
+// The options of a `// Generated by: magic ...` header, with the defaults of the magic command
+fn header_options(source: &str) -> (Piece, MagicOptions) {
+	let header = source.lines().next().unwrap();
+	let mut args = header
+		.strip_prefix("// Generated by: magic ")
+		.unwrap()
+		.split(' ');
+
+	let piece = match args.next() {
+		Some("rook") => Piece::Rook,
+		Some("bishop") => Piece::Bishop,
+		other => panic!("Unknown piece in header: {other:?}"),
+	};
+
+	let mut options = MagicOptions {
+		candidates: 16,
+		..Default::default()
+	};
+
+	while let Some(flag) = args.next() {
+		match flag {
+			"--seed" => options.seed = args.next().unwrap().parse().unwrap(),
+			"--bits" => options.fixed_bits = Some(args.next().unwrap().parse().unwrap()),
+			"--overlap" => options.overlap = true,
+			"--candidates" => options.candidates = args.next().unwrap().parse().unwrap(),
+			"--out" => {
+				args.next();
+			}
+			_ => panic!("Unknown argument in header: {flag}"),
+		}
+	}
+
+	(piece, options)
+}
+
+// The move generator is built from the generated files, searching again has to give them back
+fn assert_generated(source: &str) {
+	let (piece, options) = header_options(source);
+	let table = MagicTable::search(piece, &options).unwrap();
+
+	table.verify().unwrap();
+	assert!(source.ends_with(&table.to_rust_source()), "{piece}");
+	assert_eq!(MagicTable::current(piece).size, table.size);
+}
+
+#[test]
+fn bishop_table_matches_its_header() {
+	assert_generated(include_str!("../src/magic/bishop_magics.rs"));
+	assert!(MagicTable::current(Piece::Bishop).size < 5_248);
+}
+
+// Minutes even in release, CI runs it with --release --ignored
+#[test]
+#[ignore]
+fn rook_table_matches_its_header() {
+	assert_generated(include_str!("../src/magic/rook_magics.rs"));
+	assert!(MagicTable::current(Piece::Rook).size < 102_400);
+}
@@ -0,0 +1,103 @@
+use chess_rust_old::{Board, Move, MoveGenerator, MoveList, Square};
+
+fn start_moves() -> Vec<Move> {
+	MoveGenerator::default()
+		.all_possible(&Board::default())
+		.to_vec()
+}
+
+fn filled(moves: &[Move]) -> MoveList {
+	let mut list = MoveList::new();
+
+	for &to_move in moves {
+		list.push(to_move);
+	}
+
+	list
+}
+
+#[test]
+fn push_and_pop_work_as_a_stack() {
+	let moves = start_moves();
+	let mut list = MoveList::new();
+
+	assert!(list.is_empty());
+	assert_eq!(list.pop(), None);
+
+	list.push(moves[0]);
+	list.push(moves[1]);
+
+	assert_eq!(list.len(), 2);
+	assert_eq!(list.pop(), Some(moves[1]));
+	assert_eq!(list.pop(), Some(moves[0]));
+	assert_eq!(list.pop(), None);
+	assert!(list.is_empty());
+}
+
+#[test]
+fn iterates_and_indexes_in_push_order() {
+	let moves = start_moves();
+	let list = filled(&moves);
+
+	assert_eq!(list.len(), 20);
+	assert_eq!(list[3], moves[3]);
+	assert_eq!(list.iter().copied().collect::<Vec<_>>(), moves);
+	assert_eq!((&list).into_iter().count(), 20);
+
+	let iter = list.clone().into_iter();
+	assert_eq!(iter.len(), 20);
+	assert_eq!(iter.collect::<Vec<_>>(), moves);
+
+	//* Iterating by value consumed a clone, the list is still there */
+	assert_eq!(&*list, &moves[..]);
+}
+
+#[test]
+fn sorts_through_the_slice() {
+	let moves = start_moves();
+	let mut list = filled(&moves);
+
+	list.sort_by_key(|to_move| std::cmp::Reverse(to_move.to().to_index()));
+
+	assert!(list
+		.windows(2)
+		.all(|pair| pair[0].to().to_index() >= pair[1].to().to_index()));
+
+	list[0] = moves[0];
+	assert_eq!(list[0], moves[0]);
+}
+
+#[test]
+fn swap_remove_moves_the_last_move_into_the_gap() {
+	let moves = start_moves();
+	let mut list = filled(&moves[..4]);
+
+	assert_eq!(list.swap_remove(1), moves[1]);
+	assert_eq!(&*list, &[moves[0], moves[3], moves[2]]);
+
+	assert_eq!(list.swap_remove(2), moves[2]);
+	assert_eq!(&*list, &[moves[0], moves[3]]);
+
+	list.clear();
+	assert!(list.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 2")]
+fn swap_remove_past_the_end_panics() {
+	let moves = start_moves();
+	let mut list = filled(&moves[..2]);
+
+	list.swap_remove(2);
+}
+
+#[test]
+fn holds_the_most_moves_of_any_position() {
+	let board: Board = "R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - - 0 1"
+		.parse()
+		.unwrap();
+	let list = MoveGenerator::default().all_possible(&board);
+
+	assert_eq!(list.len(), 218);
+	assert!(list.iter().all(|to_move| to_move.from() != Square::A1));
+}
@@ -0,0 +1,43 @@
+use chess_rust_old::{Board, MoveGenerator, MoveKind, Piece, Square};
+
+fn find(fen: &str, uci: &str) -> chess_rust_old::Move {
+	let board: Board = fen.parse().unwrap();
+
+	MoveGenerator::default().parse_uci(&board, uci).unwrap()
+}
+
+#[test]
+fn enpassant_captures_a_pawn() {
+	let to_move = find("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6");
+
+	assert_eq!(to_move.kind(), MoveKind::EnPassant);
+	assert!(to_move.is_capture());
+	assert_eq!(to_move.captured(), Some(Piece::Pawn));
+	assert_eq!(to_move.to(), Square::D6);
+}
+
+#[test]
+fn captured_piece_is_reported() {
+	let to_move = find("4k3/8/8/3q4/4P3/8/8/4K3 w - - 0 1", "e4d5");
+
+	assert_eq!(to_move.kind(), MoveKind::Capture);
+	assert!(to_move.is_capture());
+	assert_eq!(to_move.captured(), Some(Piece::Queen));
+
+	let to_move = find("4k3/8/8/3q4/4P3/8/8/4K3 w - - 0 1", "e4e5");
+
+	assert_eq!(to_move.kind(), MoveKind::Quiet);
+	assert!(!to_move.is_capture());
+	assert_eq!(to_move.captured(), None);
+}
+
+#[test]
+fn moves_compare_with_assert_eq() {
+	let board = Board::default();
+	let move_gen = MoveGenerator::default();
+
+	let to_move = move_gen.parse_uci(&board, "e2e4").unwrap();
+
+	assert_eq!(move_gen.parse_san(&board, "e4").unwrap(), to_move);
+	assert_ne!(move_gen.parse_uci(&board, "d2d4").unwrap(), to_move);
+}
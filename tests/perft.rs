@@ -0,0 +1,95 @@
+use chess_rust_old::Benchmark;
+
+fn nodes_of(divide: &[(String, usize)], uci: &str) -> usize {
+	divide
+		.iter()
+		.find(|(other, _)| other == uci)
+		.map(|(_, nodes)| *nodes)
+		.unwrap_or_else(|| panic!("{uci} missing from divide"))
+}
+
+#[test]
+fn report_breaks_nodes_down_by_root_move() {
+	let mut bench = Benchmark::default();
+	let report = bench.perft(3);
+
+	assert_eq!(report.depth, 3);
+	assert_eq!(report.nodes, 8_902);
+	assert_eq!(report.divide.len(), 20);
+	assert_eq!(
+		report.divide.iter().map(|(_, nodes)| nodes).sum::<usize>(),
+		report.nodes
+	);
+	assert_eq!(nodes_of(&report.divide, "e2e4"), 600);
+	assert_eq!(nodes_of(&report.divide, "g1f3"), 440);
+}
+
+#[test]
+fn divide_uses_uci_castling_and_promotions() {
+	let mut bench = Benchmark::default();
+
+	bench.set_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+	let report = bench.perft(2);
+
+	assert_eq!(report.nodes, 2_039);
+	assert_eq!(nodes_of(&report.divide, "e1g1"), 43);
+	assert_eq!(nodes_of(&report.divide, "e1c1"), 43);
+
+	bench.set_fen("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1");
+	let report = bench.perft(1);
+
+	assert_eq!(report.nodes, 24);
+	assert_eq!(nodes_of(&report.divide, "g2h1q"), 1);
+}
+
+// Stockfish 16, "position startpos" and "go perft 2"
+const STOCKFISH_DIVIDE: &str = "a2a3: 20
+b2b3: 20
+c2c3: 20
+d2d3: 20
+e2e3: 20
+f2f3: 20
+g2g3: 20
+h2h3: 20
+a2a4: 20
+b2b4: 20
+c2c4: 20
+d2d4: 20
+e2e4: 20
+f2f4: 20
+g2g4: 20
+h2h4: 20
+b1a3: 20
+b1c3: 20
+g1f3: 20
+g1h3: 20
+
+Nodes searched: 400
+";
+
+// Every engine lists the root moves in its own order, so they are compared sorted
+fn split_divide(divide: &str) -> (Vec<&str>, &str) {
+	let (moves, total) = divide.split_once("\n\n").unwrap();
+
+	let mut moves: Vec<&str> = moves.lines().collect();
+	moves.sort_unstable();
+
+	(moves, total)
+}
+
+#[test]
+fn divide_format_matches_other_engines() {
+	let mut bench = Benchmark::default();
+	let divide = bench.perft(2).to_divide();
+
+	assert_eq!(split_divide(&divide), split_divide(STOCKFISH_DIVIDE));
+}
+
+#[test]
+fn depth_zero_counts_the_position() {
+	let mut bench = Benchmark::default();
+	let report = bench.perft(0);
+
+	assert_eq!(report.nodes, 1);
+	assert!(report.divide.is_empty());
+}
@@ -0,0 +1,72 @@
+use chess_rust_old::{Board, GameStatus, MoveGenerator};
+
+const KNIGHT_DANCE: [&str; 4] = ["g8f6", "g1f3", "f6g8", "f3g1"];
+
+fn play(board: &mut Board, moves: &[&str]) {
+	let move_gen = MoveGenerator::default();
+
+	for uci in moves {
+		let to_move = move_gen.parse_uci(board, uci).unwrap();
+		board.make_move(to_move);
+	}
+}
+
+#[test]
+fn unusable_enpassant_square_does_not_hide_threefold() {
+	let mut board = Board::default();
+
+	play(&mut board, &["e2e4"]);
+	play(&mut board, &KNIGHT_DANCE);
+	play(&mut board, &KNIGHT_DANCE);
+
+	assert_eq!(board.repetition_count(), 3);
+	assert!(board.is_threefold());
+	assert!(matches!(
+		MoveGenerator::default().game_status(&board),
+		GameStatus::ThreefoldRepetition
+	));
+}
+
+#[test]
+fn fivefold_ends_the_game() {
+	let mut board = Board::default();
+
+	play(&mut board, &["e2e4"]);
+
+	for _ in 0..4 {
+		play(&mut board, &KNIGHT_DANCE);
+	}
+
+	assert_eq!(board.repetition_count(), 5);
+	assert!(board.is_fivefold());
+	assert!(matches!(
+		MoveGenerator::default().game_status(&board),
+		GameStatus::FivefoldRepetition
+	));
+}
+
+#[test]
+fn enpassant_file_is_hashed_only_when_capturable() {
+	let hash = |fen: &str| fen.parse::<Board>().unwrap().hash();
+
+	assert_eq!(
+		hash("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1"),
+		hash("4k3/8/8/8/4P3/8/8/4K3 b - - 0 1")
+	);
+	assert_ne!(
+		hash("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1"),
+		hash("4k3/8/8/8/3pP3/8/8/4K3 b - - 0 1")
+	);
+}
+
+#[test]
+fn undo_restores_the_hash() {
+	let mut board: Board = "4k3/3p4/8/4P3/8/8/8/4K3 b - - 0 1".parse().unwrap();
+	let start = board.hash();
+
+	play(&mut board, &["d7d5", "e5d6"]);
+	board.undo_move();
+	board.undo_move();
+
+	assert_eq!(board.hash(), start);
+}
@@ -0,0 +1,123 @@
+use chess_rust_old::{Board, Error, MoveGenerator};
+
+fn san(fen: &str, uci: &str) -> String {
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+	let to_move = move_gen.parse_uci(&board, uci).unwrap();
+
+	move_gen.move_to_san(&board, to_move)
+}
+
+fn parses_to(fen: &str, san: &str, uci: &str) {
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+
+	let to_move = move_gen.parse_san(&board, san).unwrap();
+
+	assert_eq!(board.move_to_uci(to_move), uci, "{san}");
+}
+
+#[test]
+fn disambiguates_by_file_rank_or_square() {
+	let knights = "4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1";
+	assert_eq!(san(knights, "b1d2"), "Nbd2");
+	assert_eq!(san(knights, "f1d2"), "Nfd2");
+	assert_eq!(san(knights, "b1c3"), "Nc3");
+
+	let rooks = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+	assert_eq!(san(rooks, "a1a3"), "R1a3");
+	assert_eq!(san(rooks, "a5a3"), "R5a3");
+
+	let queens = "2k5/8/8/8/4Q2Q/8/K7/7Q w - - 0 1";
+	assert_eq!(san(queens, "h4e1"), "Qh4e1");
+	assert_eq!(san(queens, "e4e1"), "Qee1");
+	assert_eq!(san(queens, "h1e1"), "Q1e1");
+}
+
+#[test]
+fn castles_promotions_and_mates() {
+	assert_eq!(san("r3k3/8/8/8/8/8/8/4K3 b q - 0 1", "e8c8"), "O-O-O");
+	assert_eq!(san("4k2r/8/8/8/8/8/8/4K3 b k - 0 1", "e8g8"), "O-O");
+	assert_eq!(san("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8q"), "e8=Q+");
+	assert_eq!(san("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e7e8n"), "e8=N");
+	assert_eq!(
+		san(
+			"r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+			"h5f7"
+		),
+		"Qxf7#"
+	);
+}
+
+#[test]
+fn enpassant_suffix_is_opt_in() {
+	let fen = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1";
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+	let to_move = move_gen.parse_uci(&board, "e5d6").unwrap();
+
+	assert_eq!(move_gen.move_to_san(&board, to_move), "exd6");
+	assert_eq!(move_gen.move_to_san_with_ep(&board, to_move), "exd6 e.p.");
+
+	parses_to(fen, "exd6 e.p.", "e5d6");
+	parses_to(fen, "exd6", "e5d6");
+}
+
+#[test]
+fn lenient_parsing() {
+	parses_to("4k2r/8/8/8/8/8/8/4K3 b k - 0 1", "0-0", "e8g8");
+	parses_to("4k2r/8/8/8/8/8/8/4K3 b k - 0 1", "O-O+", "e8g8");
+	parses_to("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "ed6", "e5d6");
+	parses_to("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e8Q", "e7e8q");
+	parses_to("k7/4P3/8/8/8/8/8/4K3 w - - 0 1", "e8=Q", "e7e8q");
+	parses_to(
+		"r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+		"Qf7",
+		"h5f7",
+	);
+	parses_to("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nb1-d2", "b1d2");
+}
+
+#[test]
+fn rejects_ambiguous_and_illegal_input() {
+	let board: Board = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1".parse().unwrap();
+	let move_gen = MoveGenerator::default();
+
+	assert!(matches!(
+		move_gen.parse_san(&board, "Ra3"),
+		Err(Error::AmbiguousMove { .. })
+	));
+	assert!(matches!(
+		move_gen.parse_san(&board, "Rb3"),
+		Err(Error::IllegalMove { .. })
+	));
+	assert!(matches!(
+		move_gen.parse_san(&board, "O-O"),
+		Err(Error::IllegalMove { .. })
+	));
+	assert!(matches!(
+		move_gen.parse_san(&board, "Ra9"),
+		Err(Error::InvalidSanMove { .. })
+	));
+}
+
+#[test]
+fn every_move_round_trips() {
+	let fens = [
+		"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+		"r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+		"2k5/8/8/8/4Q2Q/8/K7/7Q w - - 0 1",
+	];
+
+	let move_gen = MoveGenerator::default();
+
+	for fen in fens {
+		let board: Board = fen.parse().unwrap();
+
+		for to_move in move_gen.all_possible(&board) {
+			let san = move_gen.move_to_san(&board, to_move);
+
+			assert_eq!(move_gen.parse_san(&board, &san).unwrap(), to_move, "{san}");
+		}
+	}
+}
@@ -0,0 +1,67 @@
+use chess_rust_old::{Board, MoveGenerator};
+
+fn see(fen: &str, uci: &str) -> i32 {
+	let move_gen = MoveGenerator::default();
+	let board: Board = fen.parse().unwrap();
+	let to_move = move_gen.parse_uci(&board, uci).unwrap();
+
+	move_gen.see(&board, to_move)
+}
+
+#[test]
+fn undefended_and_defended_pieces() {
+	assert_eq!(see("4k3/8/8/4p3/8/8/8/4R1K1 w - - 0 1", "e1e5"), 100);
+	assert_eq!(see("4k3/8/3p4/4n3/8/8/8/4R1K1 w - - 0 1", "e1e5"), -180);
+	assert_eq!(see("4k3/8/3p4/4n3/3P4/8/8/4K3 w - - 0 1", "d4e5"), 220);
+	assert_eq!(see("7k/8/8/8/8/8/8/4R1K1 w - - 0 1", "e1e5"), 0);
+}
+
+#[test]
+fn rook_battery_xray() {
+	//* The second rook only joins once the first one has captured */
+	assert_eq!(see("4k3/4r3/8/4p3/8/8/4R3/4R1K1 w - - 0 1", "e2e5"), 100);
+	assert_eq!(see("4k3/4r3/8/4p3/8/8/4R3/6K1 w - - 0 1", "e2e5"), -400);
+
+	//* Queens behind a rook and a bishop on both sides */
+	assert_eq!(
+		see(
+			"1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1",
+			"d3e5"
+		),
+		-220
+	);
+}
+
+#[test]
+fn promotion_square_captures() {
+	assert_eq!(see("r3k3/1P6/1n6/8/8/8/8/4K3 w - - 0 1", "b7a8q"), 400);
+	assert_eq!(see("r3k3/1P6/1n6/8/8/8/8/4K3 w - - 0 1", "b7a8n"), 400);
+	assert_eq!(see("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8q"), 1300);
+	assert_eq!(see("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8q"), -100);
+}
+
+#[test]
+fn en_passant() {
+	assert_eq!(see("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), 100);
+	assert_eq!(see("4k3/2p5/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), 0);
+}
+
+#[test]
+fn see_ge_thresholds() {
+	let move_gen = MoveGenerator::default();
+
+	let cases = [
+		("4k3/4r3/8/4p3/8/8/4R3/4R1K1 w - - 0 1", "e2e5", 100),
+		("4k3/8/3p4/4n3/8/8/8/4R1K1 w - - 0 1", "e1e5", -180),
+		("r3k3/1P6/1n6/8/8/8/8/4K3 w - - 0 1", "b7a8q", 400),
+	];
+
+	for (fen, uci, value) in cases {
+		let board: Board = fen.parse().unwrap();
+		let to_move = move_gen.parse_uci(&board, uci).unwrap();
+
+		assert!(move_gen.see_ge(&board, to_move, value - 1), "{fen} {uci}");
+		assert!(move_gen.see_ge(&board, to_move, value), "{fen} {uci}");
+		assert!(!move_gen.see_ge(&board, to_move, value + 1), "{fen} {uci}");
+	}
+}
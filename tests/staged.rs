@@ -0,0 +1,74 @@
+use chess_rust_old::{Board, Move, MoveGenerator};
+
+const POSITIONS: [&str; 9] = [
+	"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+	"r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+	"8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+	"r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+	"rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+	"r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+	"bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+	"r3k2r/p1pp1pb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBqPPP/R3K2R w KQkq - 0 1",
+	"4k3/8/8/8/8/5n2/8/r3K2R w K - 0 1",
+];
+
+fn same_moves(moves: &[Move], others: &[Move]) -> bool {
+	moves.len() == others.len() && moves.iter().all(|to_move| others.contains(to_move))
+}
+
+fn check_stages(board: &mut Board, move_gen: &MoveGenerator, depth: usize) {
+	let all = move_gen.all_possible(board);
+	let captures = move_gen.captures(board);
+	let quiets = move_gen.quiets(board);
+	let evasions = move_gen.evasions(board);
+
+	let fen = board.to_fen();
+
+	assert!(
+		captures
+			.iter()
+			.all(|to_move| to_move.is_capture() || to_move.promotion().is_some()),
+		"{fen}"
+	);
+	assert!(
+		quiets
+			.iter()
+			.all(|to_move| !to_move.is_capture() && to_move.promotion().is_none()),
+		"{fen}"
+	);
+	let staged: Vec<Move> = captures.iter().chain(quiets.iter()).copied().collect();
+	assert!(same_moves(&staged, &all), "{fen}\n{staged:?}\n{:?}", &*all);
+	assert_eq!(move_gen.count_legal(board), all.len(), "{fen}");
+
+	if move_gen.calculate_attack_mask(board).is_check() {
+		assert!(
+			same_moves(&evasions, &all),
+			"{fen}\n{:?}\n{:?}",
+			&*evasions,
+			&*all
+		);
+	} else {
+		assert!(evasions.is_empty(), "{fen}");
+	}
+
+	if depth == 0 {
+		return;
+	}
+
+	for to_move in all {
+		board.make_move(to_move);
+		check_stages(board, move_gen, depth - 1);
+		board.undo_move();
+	}
+}
+
+#[test]
+fn staged_generation_matches_all_possible() {
+	let move_gen = MoveGenerator::default();
+
+	for fen in POSITIONS {
+		let mut board: Board = fen.parse().unwrap();
+
+		check_stages(&mut board, &move_gen, 2);
+	}
+}
@@ -0,0 +1,77 @@
+use chess_rust_old::{Board, Error, MoveGenerator, MoveKind, Piece, Square};
+
+fn parse(fen: &str, uci: &str) -> Result<chess_rust_old::Move, Error> {
+	let board: Board = fen.parse().unwrap();
+
+	MoveGenerator::default().parse_uci(&board, uci)
+}
+
+const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[test]
+fn parses_legal_moves() {
+	let to_move = parse(START, "e2e4").unwrap();
+
+	assert_eq!(to_move.from(), Square::E2);
+	assert_eq!(to_move.to(), Square::E4);
+	assert_eq!(to_move.piece(), Piece::Pawn);
+
+	//* Surrounding whitespace and upper case are accepted */
+	let to_move = parse(START, " G1F3\n").unwrap();
+
+	assert_eq!(to_move.from(), Square::G1);
+	assert_eq!(to_move.to(), Square::F3);
+}
+
+#[test]
+fn parses_promotions_and_castling() {
+	let to_move = parse("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8n").unwrap();
+	assert_eq!(to_move.promotion(), Some(Piece::Knight));
+
+	let to_move = parse("4k3/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1").unwrap();
+	assert_eq!(to_move.kind(), MoveKind::Castle);
+
+	//* The king taking its own rook is castling too */
+	let to_move = parse("4k3/8/8/8/8/8/8/4K2R w K - 0 1", "e1h1").unwrap();
+	assert_eq!(to_move.kind(), MoveKind::Castle);
+}
+
+#[test]
+fn rejects_malformed_moves() {
+	for uci in [
+		"", "e2", "e2e", "e2e4qq", "i2e4", "e9e4", "e2e0", "e2-e4", "b7b8k", "b7b8p", "b7b8x",
+		"e2é4", "e2e4é",
+	] {
+		assert!(
+			matches!(
+				parse("4k3/1P6/8/8/8/8/4P3/4K3 w - - 0 1", uci),
+				Err(Error::InvalidUciMove { uci: ref error_uci }) if error_uci == uci
+			),
+			"{uci}"
+		);
+	}
+}
+
+#[test]
+fn rejects_illegal_moves() {
+	for uci in ["e2e5", "e1e3", "e7e5", "b1d2", "d1d3", "e1g1", "a2a3q"] {
+		assert!(
+			matches!(
+				parse(START, uci),
+				Err(Error::IllegalMove { notation: ref error_uci }) if error_uci == uci
+			),
+			"{uci}"
+		);
+	}
+
+	//* Promotions need their letter */
+	assert!(matches!(
+		parse("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8"),
+		Err(Error::IllegalMove { .. })
+	));
+	//* Pinned pieces can't move */
+	assert!(matches!(
+		parse("4r2k/8/8/8/8/8/4R3/4K3 w - - 0 1", "e2d2"),
+		Err(Error::IllegalMove { .. })
+	));
+}